@@ -1,20 +1,40 @@
-use std::fmt;
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
-use anyhow::Result;
-use reqwest::Url;
+use reqwest::{
+    StatusCode, Url,
+    header::{HeaderMap, RETRY_AFTER},
+};
 
 use crate::{
+    error::{Error, Result},
     fields::{
-        blog::BlogsEndpoint, book::BooksEndpoint, country::CountriesEndpoint,
-        disaster::DisastersEndpoint, job::JobsEndpoint, report::ReportsEndpoint,
-        source::SourcesEndpoint, training::TrainingsEndpoint,
+        blog::BlogsEndpoint,
+        book::BooksEndpoint,
+        country::CountriesEndpoint,
+        disaster::DisastersEndpoint,
+        job::JobsEndpoint,
+        reference::{References, VocabularyCache},
+        report::ReportsEndpoint,
+        source::SourcesEndpoint,
+        training::TrainingsEndpoint,
     },
     params::QueryParams,
+    rate_limit::RateLimiter,
+    response::ApiErrorResponse,
+    retry::RetryPolicy,
 };
 
 /// ReliefWeb API's public instance base URL.
 pub const RELIEFWEB_DOMAIN: &str = "api.reliefweb.int";
 
+/// Longest URL sent as a GET request by [`ResourceEndpoint::list`](crate::ResourceEndpoint::list),
+/// beyond which the query is sent as a JSON body in a POST request instead.
+pub const MAX_GET_URL_LENGTH: usize = 4096;
+
 /// A client for interacting with the ReliefWeb API.
 ///
 /// # Examples
@@ -24,7 +44,18 @@ pub const RELIEFWEB_DOMAIN: &str = "api.reliefweb.int";
 ///
 /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
 /// let reports_endpoint = client.reports();
+/// ```
+///
+/// Cloning a `Client` is cheap, a single reference count increment: clones share their configuration, the connection
+/// pool, the [`RateLimiter`] and the cache of reference vocabularies, so one client can be cloned into each task of a
+/// concurrent harvest.
+#[derive(Clone)]
 pub struct Client {
+    pub(crate) inner: Arc<ClientInner>,
+}
+
+/// The immutable configuration and shared state of a [`Client`].
+pub(crate) struct ClientInner {
     /// Base URL for the API.
     pub(crate) api_base: Url,
 
@@ -33,13 +64,27 @@ pub struct Client {
 
     /// The application name to identify your requests.
     pub(crate) app_name: String,
+
+    /// Policy applied to retry failed requests.
+    pub(crate) retry_policy: RetryPolicy,
+
+    /// Optional limiter throttling requests sent by this client.
+    pub(crate) rate_limiter: Option<RateLimiter>,
+
+    /// Whether `list` requests reject field names unknown for their resource.
+    pub(crate) validate_fields: bool,
+
+    /// Reference vocabularies fetched by this client, by resource.
+    pub(crate) references_cache: VocabularyCache,
 }
 
 /// The API specification version.
 /// V1 is deprecated and should no longer be used.
 /// The V2 version is fully compatible with the V1 version.
+#[derive(Default)]
 pub enum APIVersion {
     V1,
+    #[default]
     V2,
 }
 
@@ -52,16 +97,203 @@ impl fmt::Display for APIVersion {
     }
 }
 
+/// A builder to configure and create a [`Client`].
+///
+/// By default, the builder targets the API over HTTPS with the [`APIVersion::V2`] specification,
+/// and uses a `reqwest::Client` without timeouts.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use reliefweb::{Client, RELIEFWEB_DOMAIN};
+///
+/// let client = Client::builder(RELIEFWEB_DOMAIN, "my_app")
+///     .connect_timeout(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(30))
+///     .user_agent("my_app/1.0")
+///     .build()
+///     .unwrap();
+/// ```
+pub struct ClientBuilder {
+    scheme: String,
+    domain: String,
+    app_name: String,
+    version: APIVersion,
+    http_client: Option<reqwest::Client>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    default_headers: HeaderMap,
+    user_agent: Option<String>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    validate_fields: bool,
+    #[cfg(feature = "gzip")]
+    gzip: Option<bool>,
+    #[cfg(feature = "brotli")]
+    brotli: Option<bool>,
+}
+
+impl ClientBuilder {
+    /// Create a new builder for a client targeting the given domain, identified by the given application name.
+    pub fn new(domain: &str, app_name: &str) -> Self {
+        Self {
+            scheme: "https".to_string(),
+            domain: domain.to_string(),
+            app_name: app_name.to_string(),
+            version: APIVersion::default(),
+            http_client: None,
+            connect_timeout: None,
+            timeout: None,
+            proxies: Vec::new(),
+            default_headers: HeaderMap::new(),
+            user_agent: None,
+            retry_policy: RetryPolicy::none(),
+            rate_limiter: None,
+            validate_fields: false,
+            #[cfg(feature = "gzip")]
+            gzip: None,
+            #[cfg(feature = "brotli")]
+            brotli: None,
+        }
+    }
+
+    /// Set the transport scheme, e.g. `http` or `https`. Defaults to `https`.
+    pub fn scheme(mut self, scheme: &str) -> Self {
+        self.scheme = scheme.to_string();
+        self
+    }
+
+    /// Set the API specification version. Defaults to [`APIVersion::V2`].
+    pub fn version(mut self, version: APIVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Set a timeout for the connect phase of each request.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set a timeout for each request, from connecting until the response body has finished.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Route requests through the given proxy. Can be called multiple times to add several proxies.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Set headers sent with every request. Extends previously set default headers.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    /// Set the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Set the policy used to retry failed `list` and `get` requests. By default, requests are not retried.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Throttle requests, including retries, with the given rate limiter. By default, requests are not throttled.
+    pub fn rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Enable or disable the check of field names before `list` requests are sent. Disabled by default, as the field
+    /// lists of this client may lag behind the API. See [`QueryParams::validate_fields_for`].
+    pub fn validate_fields(mut self, enable: bool) -> Self {
+        self.validate_fields = enable;
+        self
+    }
+
+    /// Enable or disable automatic decompression of gzip responses.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(mut self, enable: bool) -> Self {
+        self.gzip = Some(enable);
+        self
+    }
+
+    /// Enable or disable automatic decompression of brotli responses.
+    #[cfg(feature = "brotli")]
+    pub fn brotli(mut self, enable: bool) -> Self {
+        self.brotli = Some(enable);
+        self
+    }
+
+    /// Use a preconfigured `reqwest::Client` to send requests.
+    ///
+    /// When set, timeouts, proxies, headers, user agent and compression settings of this builder are ignored,
+    /// and the given client is used as-is.
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Create the [`Client`] from this configuration.
+    pub fn build(self) -> Result<Client> {
+        let api_base = Url::parse(&format!(
+            "{}://{}/{}/",
+            self.scheme, self.domain, self.version
+        ))?;
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = reqwest::Client::builder().default_headers(self.default_headers);
+                if let Some(t) = self.connect_timeout {
+                    builder = builder.connect_timeout(t);
+                }
+                if let Some(t) = self.timeout {
+                    builder = builder.timeout(t);
+                }
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+                if let Some(ua) = self.user_agent {
+                    builder = builder.user_agent(ua);
+                }
+                #[cfg(feature = "gzip")]
+                if let Some(enable) = self.gzip {
+                    builder = builder.gzip(enable);
+                }
+                #[cfg(feature = "brotli")]
+                if let Some(enable) = self.brotli {
+                    builder = builder.brotli(enable);
+                }
+                builder.build()?
+            }
+        };
+        Ok(Client {
+            inner: Arc::new(ClientInner {
+                api_base,
+                client,
+                app_name: self.app_name,
+                retry_policy: self.retry_policy,
+                rate_limiter: self.rate_limiter,
+                validate_fields: self.validate_fields,
+                references_cache: VocabularyCache::default(),
+            }),
+        })
+    }
+}
+
 impl Client {
     /// Create a new instance of client with the given domain, application name and specification version, using HTTPS transport.
     pub fn new(domain: &str, app_name: &str, version: APIVersion) -> Result<Client> {
-        let api_base = Url::parse(format!("https://{domain}/{version}/").as_str())?;
-        let client = reqwest::Client::new();
-        Ok(Client {
-            api_base,
-            client,
-            app_name: app_name.to_string(),
-        })
+        Self::builder(domain, app_name).version(version).build()
     }
 
     /// Create a new instance of client with the given transport scheme, domain, application name and specification version.
@@ -71,13 +303,20 @@ impl Client {
         app_name: &str,
         version: APIVersion,
     ) -> Result<Client> {
-        let api_base = Url::parse(&format!("{scheme}://{domain}/{version}/"))?;
-        let client = reqwest::Client::new();
-        Ok(Client {
-            api_base,
-            client,
-            app_name: app_name.to_string(),
-        })
+        Self::builder(domain, app_name)
+            .scheme(scheme)
+            .version(version)
+            .build()
+    }
+
+    /// Returns a [`ClientBuilder`] to configure a client for the given domain and application name.
+    pub fn builder(domain: &str, app_name: &str) -> ClientBuilder {
+        ClientBuilder::new(domain, app_name)
+    }
+
+    /// Returns the [`RateLimiter`] throttling this client, if any, e.g. to inspect the remaining budget.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.inner.rate_limiter.as_ref()
     }
 
     /// Returns the [`ReportsEndpoint`] to interact with the `reports` API.
@@ -87,11 +326,13 @@ impl Client {
     /// ```no_run
     /// use reliefweb::{Client, APIVersion, QueryParams};
     ///
+    /// # async fn run() {
     /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
     /// let reports = client.reports()
     ///     .list(Some(&QueryParams::new().limit(10)))
     ///     .await
     ///     .unwrap();
+    /// # }
     /// ```
     pub fn reports(&'_ self) -> ReportsEndpoint<'_> {
         ReportsEndpoint::new(self, "reports")
@@ -104,11 +345,13 @@ impl Client {
     /// ```no_run
     /// use reliefweb::{Client, APIVersion, QueryParams};
     ///
+    /// # async fn run() {
     /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
     /// let disasters = client.disasters()
     ///     .list(Some(&QueryParams::new().limit(10)))
     ///     .await
     ///     .unwrap();
+    /// # }
     /// ```
     pub fn disasters(&'_ self) -> DisastersEndpoint<'_> {
         DisastersEndpoint::new(self, "disasters")
@@ -121,11 +364,13 @@ impl Client {
     /// ```no_run
     /// use reliefweb::{Client, APIVersion, QueryParams};
     ///
+    /// # async fn run() {
     /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
     /// let countries = client.countries()
     ///     .list(Some(&QueryParams::new().limit(10)))
     ///     .await
     ///     .unwrap();
+    /// # }
     /// ```
     pub fn countries(&'_ self) -> CountriesEndpoint<'_> {
         CountriesEndpoint::new(self, "countries")
@@ -138,11 +383,13 @@ impl Client {
     /// ```no_run
     /// use reliefweb::{Client, APIVersion, QueryParams};
     ///
+    /// # async fn run() {
     /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
     /// let jobs = client.jobs()
     ///     .list(Some(&QueryParams::new().limit(10)))
     ///     .await
     ///     .unwrap();
+    /// # }
     /// ```
     pub fn jobs(&'_ self) -> JobsEndpoint<'_> {
         JobsEndpoint::new(self, "jobs")
@@ -155,11 +402,13 @@ impl Client {
     /// ```no_run
     /// use reliefweb::{Client, APIVersion, QueryParams};
     ///
+    /// # async fn run() {
     /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
-    /// let trainings = client.trainings()
+    /// let trainings = client.training()
     ///     .list(Some(&QueryParams::new().limit(10)))
     ///     .await
     ///     .unwrap();
+    /// # }
     /// ```
     pub fn training(&'_ self) -> TrainingsEndpoint<'_> {
         TrainingsEndpoint::new(self, "training")
@@ -172,11 +421,13 @@ impl Client {
     /// ```no_run
     /// use reliefweb::{Client, APIVersion, QueryParams};
     ///
+    /// # async fn run() {
     /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
     /// let sources = client.sources()
     ///     .list(Some(&QueryParams::new().limit(10)))
     ///     .await
     ///     .unwrap();
+    /// # }
     /// ```
     pub fn sources(&'_ self) -> SourcesEndpoint<'_> {
         SourcesEndpoint::new(self, "sources")
@@ -189,11 +440,13 @@ impl Client {
     /// ```no_run
     /// use reliefweb::{Client, APIVersion, QueryParams};
     ///
+    /// # async fn run() {
     /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
-    /// let posts = client.blogs()
+    /// let posts = client.blog()
     ///     .list(Some(&QueryParams::new().limit(10)))
     ///     .await
     ///     .unwrap();
+    /// # }
     /// ```
     pub fn blog(&'_ self) -> BlogsEndpoint<'_> {
         BlogsEndpoint::new(self, "blog")
@@ -206,31 +459,195 @@ impl Client {
     /// ```no_run
     /// use reliefweb::{Client, APIVersion, QueryParams};
     ///
+    /// # async fn run() {
     /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
-    /// let books = client.books()
+    /// let books = client.book()
     ///     .list(Some(&QueryParams::new().limit(10)))
     ///     .await
     ///     .unwrap();
+    /// # }
     /// ```
     pub fn book(&'_ self) -> BooksEndpoint<'_> {
         BooksEndpoint::new(self, "book")
     }
 
+    /// Returns the [`References`] accessor to interact with the `references` APIs, such as themes or languages.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use reliefweb::{Client, APIVersion, QueryParams};
+    ///
+    /// # async fn run() {
+    /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
+    /// let languages = client.references()
+    ///     .languages()
+    ///     .list(Some(&QueryParams::new().limit(10)))
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn references(&'_ self) -> References<'_> {
+        References::new(self)
+    }
+
     /// Constructs a GET request to the API with the given endpoint and params.
     /// Includes the `app_name` specified on Client creation as a query parameter.
     pub(crate) fn get_with_params(
+        &self,
+        endpoint: Url,
+        params: Option<&QueryParams>,
+    ) -> reqwest::RequestBuilder {
+        self.inner
+            .client
+            .get(self.url_with_params(endpoint, params))
+    }
+
+    /// Constructs a POST request to the API with the given endpoint, sending params as a JSON body.
+    /// Includes the `app_name` specified on Client creation, and `verbose` if set, as query parameters.
+    pub(crate) fn post_with_params(
         &self,
         mut endpoint: Url,
+        params: &QueryParams,
+    ) -> reqwest::RequestBuilder {
+        {
+            let mut qp = endpoint.query_pairs_mut();
+            qp.append_pair("appname", &self.inner.app_name);
+            if let Some(v) = params.verbose {
+                qp.append_pair("verbose", if v { "1" } else { "0" });
+            }
+        }
+        self.inner.client.post(endpoint).json(&params.to_json())
+    }
+
+    /// Constructs a `list` request, switching from GET to POST when the GET URL would be longer than [`MAX_GET_URL_LENGTH`].
+    pub(crate) fn list_request(
+        &self,
+        endpoint: Url,
         params: Option<&QueryParams>,
     ) -> reqwest::RequestBuilder {
+        let url = self.url_with_params(endpoint.clone(), params);
+        match params {
+            Some(p) if url.as_str().len() > MAX_GET_URL_LENGTH => {
+                self.post_with_params(endpoint, p)
+            }
+            _ => self.inner.client.get(url),
+        }
+    }
+
+    /// Appends the `app_name` and the given params to the endpoint URL.
+    fn url_with_params(&self, mut endpoint: Url, params: Option<&QueryParams>) -> Url {
         endpoint
             .query_pairs_mut()
-            .append_pair("appname", &self.app_name);
+            .append_pair("appname", &self.inner.app_name);
         if let Some(p) = params {
             p.apply_to_url(&mut endpoint);
         }
-        self.client.get(endpoint)
+        endpoint
     }
+
+    /// Sends the given request, retrying it according to the client's [`RetryPolicy`].
+    pub(crate) async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let request = request.build()?;
+        let mut attempt = 1;
+        loop {
+            let Some(req) = request.try_clone() else {
+                return self.send_once(request).await;
+            };
+            match self.send_once(req).await {
+                Err(err)
+                    if attempt < self.inner.retry_policy.max_attempts
+                        && self.inner.retry_policy.is_retryable(&err) =>
+                {
+                    tokio::time::sleep(self.inner.retry_policy.delay(attempt, &err)).await;
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    /// Sends the given request once and turns non-success HTTP statuses into errors.
+    async fn send_once(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        if let Some(limiter) = &self.inner.rate_limiter {
+            limiter.acquire().await?;
+        }
+        let resp = self.inner.client.execute(request).await?;
+        let status = resp.status();
+        if status.is_success() {
+            return Ok(resp);
+        }
+        let retry_after = retry_after(resp.headers());
+        let body = resp.text().await?;
+        let error = serde_json::from_str::<ApiErrorResponse>(&body)
+            .ok()
+            .filter(|error| error.error.is_some());
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::RateLimited { retry_after, error });
+        }
+        match error {
+            Some(error) => Err(Error::Api {
+                status,
+                error,
+                retry_after,
+            }),
+            None => Err(Error::Status {
+                status,
+                body,
+                retry_after,
+            }),
+        }
+    }
+}
+
+/// Parses a `Retry-After` header, expressed either in seconds or as an HTTP date.
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = parse_http_date(value)?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Parses an HTTP date in the IMF-fixdate format, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+    let rest = value.split_once(", ")?.1.strip_suffix(" GMT")?;
+    let [day, month, year, time] = rest.split(' ').collect::<Vec<_>>()[..] else {
+        return None;
+    };
+    let month = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ]
+    .iter()
+    .position(|&m| m == month)? as i64
+        + 1;
+    let (day, year): (i64, i64) = (day.parse().ok()?, year.parse().ok()?);
+    let [hours, minutes, seconds] = time
+        .split(':')
+        .map(|part| part.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>()?[..]
+    else {
+        return None;
+    };
+    if !(1..=31).contains(&day) || hours > 23 || minutes > 59 || seconds > 60 {
+        return None;
+    }
+    // Days since the Unix epoch of the civil date, counting years from March so leap days come last.
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = y.div_euclid(400);
+    let year_of_era = y - era * 400;
+    let day_of_year = (153 * m + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = u64::try_from(era * 146_097 + day_of_era - 719_468).ok()?;
+    Some(UNIX_EPOCH + Duration::from_secs(days * 86_400 + hours * 3600 + minutes * 60 + seconds))
 }
 
 #[cfg(test)]
@@ -246,25 +663,233 @@ mod tests {
         let app_name = "reliefweb_rust_tests";
         let c1 = Client::new(RELIEFWEB_DOMAIN, app_name, APIVersion::V1).unwrap();
         assert_eq!(
-            c1.api_base.as_str(),
+            c1.inner.api_base.as_str(),
             format!("https://{RELIEFWEB_DOMAIN}/v1/")
         );
         let c2 = Client::new(RELIEFWEB_DOMAIN, app_name, APIVersion::V2).unwrap();
         assert_eq!(
-            c2.api_base.as_str(),
+            c2.inner.api_base.as_str(),
             format!("https://{RELIEFWEB_DOMAIN}/v2/")
         );
-        assert_eq!(c2.app_name, app_name);
+        assert_eq!(c2.inner.app_name, app_name);
         let result = Client::new("not a url", "app", APIVersion::V2);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn builder_init() {
+        let client = Client::builder(RELIEFWEB_DOMAIN, "app")
+            .version(APIVersion::V1)
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.inner.api_base.as_str(),
+            format!("https://{RELIEFWEB_DOMAIN}/v1/")
+        );
+        assert_eq!(client.inner.app_name, "app");
+
+        let client = Client::builder("localhost:8080", "app")
+            .scheme("http")
+            .http_client(reqwest::Client::new())
+            .build()
+            .unwrap();
+        assert_eq!(client.inner.api_base.as_str(), "http://localhost:8080/v2/");
+    }
+
+    #[tokio::test]
+    async fn builder_headers() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v2/reports")
+                .header("user-agent", "reliefweb-tests/1.0")
+                .header("x-api-key", "secret");
+            then.status(200)
+                .json_body_obj(&serde_json::json!({ "data": [] }));
+        });
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", "secret".parse().unwrap());
+        let client = Client::builder(&format!("{}:{}", server.host(), server.port()), "app")
+            .scheme("http")
+            .user_agent("reliefweb-tests/1.0")
+            .default_headers(headers)
+            .build()
+            .unwrap();
+
+        client.reports().list(None).await.unwrap();
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn builder_timeout() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/v2/reports");
+            then.status(200)
+                .delay(std::time::Duration::from_millis(500))
+                .json_body_obj(&serde_json::json!({ "data": [] }));
+        });
+
+        let client = Client::builder(&format!("{}:{}", server.host(), server.port()), "app")
+            .scheme("http")
+            .timeout(std::time::Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let err = client.reports().list(None).await.unwrap_err();
+        assert!(matches!(err, Error::Transport(e) if e.is_timeout()));
+    }
+
+    #[tokio::test]
+    async fn retries_transient_errors() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let mut unavailable = server.mock(|when, then| {
+            when.method(GET).path("/v2/reports");
+            then.status(503).body("Service Unavailable");
+        });
+        let missing = server.mock(|when, then| {
+            when.method(GET).path("/v2/reports/1");
+            then.status(404).body("Not Found");
+        });
+
+        let client = Client::builder(&format!("{}:{}", server.host(), server.port()), "app")
+            .scheme("http")
+            .retry_policy(
+                RetryPolicy::new()
+                    .max_attempts(3)
+                    .initial_backoff(std::time::Duration::from_millis(1)),
+            )
+            .build()
+            .unwrap();
+
+        let err = client.reports().list(None).await.unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+        unavailable.assert_hits(3);
+        unavailable.delete();
+
+        let unavailable = server.mock(|when, then| {
+            when.method(GET).path("/v2/reports");
+            then.status(503)
+                .header("Retry-After", "Thu, 01 Jan 1970 00:00:00 GMT")
+                .body("Service Unavailable");
+        });
+        let err = client.reports().list(None).await.unwrap_err();
+        assert_eq!(err.retry_after(), Some(Duration::ZERO));
+        unavailable.assert_hits(3);
+
+        let err = client
+            .reports()
+            .get("1", None, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::NOT_FOUND));
+        missing.assert_hits(1);
+    }
+
+    #[test]
+    fn parses_retry_after() {
+        let header = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(RETRY_AFTER, value.parse().unwrap());
+            retry_after(&headers)
+        };
+        assert_eq!(header("120"), Some(Duration::from_secs(120)));
+        assert_eq!(
+            header("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(header("soon"), None);
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(UNIX_EPOCH + Duration::from_secs(784_111_777))
+        );
+        assert_eq!(
+            parse_http_date("Tue, 29 Feb 2028 23:59:59 GMT"),
+            Some(UNIX_EPOCH + Duration::from_secs(1_835_481_599))
+        );
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 UTC"), None);
+
+        let far = header("Fri, 01 Jan 2100 00:00:00 GMT").unwrap();
+        assert!(far > Duration::from_secs(70 * 365 * 86_400));
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_quota() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v2/reports");
+            then.status(200)
+                .json_body_obj(&serde_json::json!({ "data": [] }));
+        });
+
+        let limiter = RateLimiter::new(10, 2).fail_on_quota(true);
+        let client = Client::builder(&format!("{}:{}", server.host(), server.port()), "app")
+            .scheme("http")
+            .rate_limiter(limiter.clone())
+            .build()
+            .unwrap();
+
+        client.reports().list(None).await.unwrap();
+        client.reports().list(None).await.unwrap();
+        let err = client.reports().list(None).await.unwrap_err();
+        assert!(matches!(err, Error::QuotaExceeded { .. }));
+        assert_eq!(client.rate_limiter().unwrap().remaining().daily, Some(0));
+        mock.assert_hits(2);
+    }
+
+    #[test]
+    fn post_with_params() {
+        let client = Client::new(RELIEFWEB_DOMAIN, "app", APIVersion::V2).unwrap();
+        let params = QueryParams::new().verbose(true).limit(3);
+        let request = client
+            .post_with_params(
+                Url::parse(&format!("{}reports", client.inner.api_base)).unwrap(),
+                &params,
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(request.method(), reqwest::Method::POST);
+        assert_eq!(request.url().query(), Some("appname=app&verbose=1"));
+        let body: serde_json::Value =
+            serde_json::from_slice(request.body().unwrap().as_bytes().unwrap()).unwrap();
+        assert_eq!(body, serde_json::json!({ "limit": 3 }));
+    }
+
+    #[test]
+    fn list_request_switches_to_post() {
+        let client = Client::new(RELIEFWEB_DOMAIN, "app", APIVersion::V2).unwrap();
+        let endpoint = Url::parse(&format!("{}reports", client.inner.api_base)).unwrap();
+
+        let short = QueryParams::new().include(vec!["title".to_string()]);
+        let request = client
+            .list_request(endpoint.clone(), Some(&short))
+            .build()
+            .unwrap();
+        assert_eq!(request.method(), reqwest::Method::GET);
+
+        let long = QueryParams::new().include(vec!["title".repeat(1000)]);
+        let request = client.list_request(endpoint, Some(&long)).build().unwrap();
+        assert_eq!(request.method(), reqwest::Method::POST);
+        assert_eq!(request.url().query(), Some("appname=app"));
+    }
+
     #[test]
     fn get_with_params_none() {
         let client = Client::new(RELIEFWEB_DOMAIN, "app", APIVersion::V2).unwrap();
         let request = client
             .get_with_params(
-                Url::parse(&format!("{}/reports", client.api_base)).unwrap(),
+                Url::parse(&format!("{}/reports", client.inner.api_base)).unwrap(),
                 None,
             )
             .build()
@@ -289,7 +914,7 @@ mod tests {
         let params = QueryParams::new().query(query);
         let request = client
             .get_with_params(
-                Url::parse(format!("{}reports", client.api_base).as_str()).unwrap(),
+                Url::parse(format!("{}reports", client.inner.api_base).as_str()).unwrap(),
                 Some(&params),
             )
             .build()
@@ -328,7 +953,7 @@ mod tests {
         let params = QueryParams::new().query(query);
         let request = client
             .get_with_params(
-                Url::parse(&format!("{}reports", client.api_base)).unwrap(),
+                Url::parse(&format!("{}reports", client.inner.api_base)).unwrap(),
                 Some(&params),
             )
             .build()
@@ -360,5 +985,20 @@ mod tests {
         assert_eq!(sources.resource(), "sources");
         assert_eq!(blog.resource(), "blog");
         assert_eq!(book.resource(), "book");
+
+        let references = client.references();
+        assert_eq!(
+            references.career_categories().resource(),
+            "references/career-categories"
+        );
+        assert_eq!(
+            references.disaster_types().resource(),
+            "references/disaster-types"
+        );
+        assert_eq!(references.themes().resource(), "references/themes");
+        assert_eq!(
+            references.vulnerable_groups().resource(),
+            "references/vulnerable-groups"
+        );
     }
 }
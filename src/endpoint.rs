@@ -1,8 +1,10 @@
-use anyhow::Result;
+use std::borrow::Cow;
+
 use serde::de::DeserializeOwned;
 
 use crate::{
     Client,
+    error::Result,
     params::{QueryParams, QueryProfile},
     response::ApiResponse,
 };
@@ -18,17 +20,25 @@ use crate::{
 /// use reliefweb::{Client, ResourceEndpoint, QueryParams};
 /// use serde_json::Value;
 ///
+/// # async fn run() {
 /// let client = Client::new("api.reliefweb.int", "my_app", reliefweb::APIVersion::V2).unwrap();
 /// let reports_endpoint: ResourceEndpoint<Value> = ResourceEndpoint::new(&client, "reports");
 ///
 /// let list = reports_endpoint.list(Some(&QueryParams::new().limit(5))).await.unwrap();
+/// # }
 /// ```
+///
+/// Endpoints borrow the client they are created from. Use [`ResourceEndpoint::into_owned`] to get an
+/// [`OwnedResourceEndpoint`], e.g. to move it into a spawned task or store it in long-lived state.
 pub struct ResourceEndpoint<'c, T> {
-    client: &'c Client,
-    resource: &'static str,
+    pub(crate) client: Cow<'c, Client>,
+    pub(crate) resource: &'static str,
     _marker: std::marker::PhantomData<T>,
 }
 
+/// A [`ResourceEndpoint`] owning its client, as returned by [`ResourceEndpoint::into_owned`].
+pub type OwnedResourceEndpoint<T> = ResourceEndpoint<'static, T>;
+
 impl<'c, T> ResourceEndpoint<'c, T>
 where
     T: DeserializeOwned,
@@ -36,7 +46,7 @@ where
     /// Creates a new endpoint instance from the given `Client`, accessible at `{base_endpoint}/resource`
     pub fn new(client: &'c Client, resource: &'static str) -> Self {
         Self {
-            client,
+            client: Cow::Borrowed(client),
             resource,
             _marker: std::marker::PhantomData,
         }
@@ -45,16 +55,45 @@ where
     /// Execute a `list` request to the endpoint.
     ///
     /// Use `options` to specify all supported query options for the request.
+    ///
+    /// The request is sent with GET, unless the resulting URL is longer than [`MAX_GET_URL_LENGTH`](crate::MAX_GET_URL_LENGTH),
+    /// in which case the parameters are sent as a JSON body with POST, like [`ResourceEndpoint::list_post`].
+    ///
+    /// `params` are checked with [`QueryParams::validate`] first, failing with [`Error::Validation`](crate::Error::Validation)
+    /// without sending the request if they are invalid. Field names are only checked, with
+    /// [`QueryParams::validate_fields_for`], when enabled with [`ClientBuilder::validate_fields`](crate::ClientBuilder::validate_fields).
     pub async fn list(&self, params: Option<&QueryParams>) -> Result<ApiResponse<T>> {
-        let endpoint = self.client.api_base.join(self.resource)?;
+        if let Some(params) = params {
+            self.validate(params)?;
+        }
+        let endpoint = self.client.inner.api_base.join(self.resource)?;
+        let resp = self
+            .client
+            .send(self.client.list_request(endpoint, params))
+            .await?;
+        decode(resp).await
+    }
+
+    /// Execute a `list` request to the endpoint, sending `params` as a JSON body with POST.
+    ///
+    /// This avoids URL length limits for large queries, and is otherwise equivalent to [`ResourceEndpoint::list`].
+    pub async fn list_post(&self, params: &QueryParams) -> Result<ApiResponse<T>> {
+        self.validate(params)?;
+        let endpoint = self.client.inner.api_base.join(self.resource)?;
         let resp = self
             .client
-            .get_with_params(endpoint, params)
-            .send()
-            .await?
-            .json::<ApiResponse<T>>()
+            .send(self.client.post_with_params(endpoint, params))
             .await?;
-        Ok(resp)
+        decode(resp).await
+    }
+
+    /// Check `params` before a `list` request, including field names when enabled on the client.
+    fn validate(&self, params: &QueryParams) -> Result<()> {
+        if self.client.inner.validate_fields {
+            params.validate_for(self.resource)
+        } else {
+            params.validate()
+        }
     }
 
     /// Execute a `get` request for a specific resource `id` on the endpoint.
@@ -70,6 +109,7 @@ where
     ) -> Result<ApiResponse<T>> {
         let endpoint = self
             .client
+            .inner
             .api_base
             .join(&format!("{}/{}", self.resource, id))?;
         let mut params = QueryParams::new();
@@ -85,16 +125,54 @@ where
 
         let resp = self
             .client
-            .get_with_params(endpoint, Some(&params))
-            .send()
-            .await?
-            .json::<ApiResponse<T>>()
+            .send(self.client.get_with_params(endpoint, Some(&params)))
             .await?;
-        Ok(resp)
+        decode(resp).await
     }
 }
 
+impl<T> Clone for ResourceEndpoint<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            resource: self.resource,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Deserializes a response body, reporting the JSON path of the offending value on failure.
+async fn decode<T: DeserializeOwned>(resp: reqwest::Response) -> Result<ApiResponse<T>> {
+    let bytes = resp.bytes().await?;
+    let de = &mut serde_json::Deserializer::from_slice(&bytes);
+    Ok(serde_path_to_error::deserialize(de)?)
+}
+
 impl<'c, T> ResourceEndpoint<'c, T> {
+    /// Returns an endpoint owning a clone of its client, which can outlive the original client.
+    ///
+    /// Cloning the client is cheap, and the clone shares its configuration, connection pool, rate limiter and reference cache.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use reliefweb::{Client, APIVersion, QueryParams};
+    ///
+    /// # async fn run() {
+    /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
+    /// let reports = client.reports().into_owned();
+    /// let task = tokio::spawn(async move { reports.list(Some(&QueryParams::new().limit(5))).await });
+    /// let list = task.await.unwrap().unwrap();
+    /// # }
+    /// ```
+    pub fn into_owned(self) -> OwnedResourceEndpoint<T> {
+        ResourceEndpoint {
+            client: Cow::Owned(self.client.into_owned()),
+            resource: self.resource,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
     #[cfg(test)]
     pub fn resource(&self) -> &'static str {
         self.resource
@@ -167,4 +245,309 @@ mod tests {
         assert_eq!(resp.data[0].fields.title, Some("Report 123".to_string()));
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_list_post_reports() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/v2/reports")
+                .query_param("appname", "testapp")
+                .json_body(serde_json::json!({ "limit": 1, "sort": ["date.created:desc"] }));
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": [
+                    { "id": "1", "score" : 1, "fields": { "title": "Report 1" } }
+                ]
+            }));
+        });
+
+        let client = Client::new_with_scheme(
+            "http",
+            format!("{}:{}", server.host(), server.port()).as_str(),
+            "testapp",
+            crate::APIVersion::V2,
+        )
+        .unwrap();
+
+        let params = crate::QueryParams::new()
+            .limit(1)
+            .sort(vec![crate::SortDescriptor {
+                field: "date.created".to_string(),
+                direction: crate::SortDirection::Desc,
+            }]);
+        let resp = client.reports().list_post(&params).await.unwrap();
+
+        assert_eq!(resp.data[0].fields.title, Some("Report 1".to_string()));
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_owned_endpoint_in_spawned_tasks() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v2/reports");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": [{ "id": "1", "fields": { "title": "Report 1" } }]
+            }));
+        });
+
+        let limiter = crate::RateLimiter::new(10, 100);
+        let client = Client::builder(
+            format!("{}:{}", server.host(), server.port()).as_str(),
+            "testapp",
+        )
+        .scheme("http")
+        .rate_limiter(limiter.clone())
+        .build()
+        .unwrap();
+
+        let reports: crate::OwnedResourceEndpoint<crate::report::ReportFields> =
+            client.reports().into_owned();
+        drop(client);
+        assert!(std::sync::Arc::ptr_eq(
+            &reports.client.inner,
+            &reports.clone().client.inner
+        ));
+        let tasks: Vec<_> = (0..2)
+            .map(|_| {
+                let reports = reports.clone();
+                tokio::spawn(async move { reports.list(None).await })
+            })
+            .collect();
+        for task in tasks {
+            let resp = task.await.unwrap().unwrap();
+            assert_eq!(resp.data[0].fields.title, Some("Report 1".to_string()));
+        }
+
+        mock.assert_hits(2);
+        assert_eq!(limiter.remaining().daily, Some(98));
+    }
+
+    #[tokio::test]
+    async fn test_decode_error_path() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v2/reports");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": [
+                    { "id": "1", "fields": { "title": 42 } }
+                ]
+            }));
+        });
+
+        let client = Client::new_with_scheme(
+            "http",
+            format!("{}:{}", server.host(), server.port()).as_str(),
+            "testapp",
+            crate::APIVersion::V2,
+        )
+        .unwrap();
+
+        let err = client.reports().list(None).await.unwrap_err();
+
+        match err {
+            crate::Error::Decode { path, .. } => assert_eq!(path, "data[0].fields.title"),
+            other => panic!("expected a decode error, got {other:?}"),
+        }
+        mock.assert();
+    }
+
+    fn client(server: &httpmock::MockServer) -> Client {
+        Client::new_with_scheme(
+            "http",
+            format!("{}:{}", server.host(), server.port()).as_str(),
+            "testapp",
+            crate::APIVersion::V2,
+        )
+        .unwrap()
+    }
+
+    fn error_body(status: u16, error_type: &str, message: &str) -> serde_json::Value {
+        serde_json::json!({
+            "time": 1,
+            "status": status,
+            "error": { "type": error_type, "message": message }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_api_error_bad_request() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v2/reports");
+            then.status(400).json_body_obj(&error_body(
+                400,
+                "InvalidParameterException",
+                "Invalid filter field",
+            ));
+        });
+
+        let err = client(&server).reports().list(None).await.unwrap_err();
+
+        match err {
+            crate::Error::Api { status, error, .. } => {
+                assert_eq!(status.as_u16(), 400);
+                assert_eq!(error.status, Some(400));
+                assert_eq!(
+                    error.error.unwrap().error_type.as_deref(),
+                    Some("InvalidParameterException")
+                );
+            }
+            other => panic!("expected an API error, got {other:?}"),
+        }
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_api_error_not_found() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v2/reports/999");
+            then.status(404).json_body_obj(&error_body(
+                404,
+                "NotFoundHttpException",
+                "Item not found",
+            ));
+        });
+
+        let err = client(&server)
+            .reports()
+            .get("999", None, None, None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.status().map(|s| s.as_u16()), Some(404));
+        match err {
+            crate::Error::Api { error, .. } => assert_eq!(error.message(), Some("Item not found")),
+            other => panic!("expected an API error, got {other:?}"),
+        }
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_api_error_rate_limited() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v2/reports");
+            then.status(429)
+                .header("Retry-After", "30")
+                .json_body_obj(&error_body(
+                    429,
+                    "TooManyRequestsHttpException",
+                    "Slow down",
+                ));
+        });
+
+        let err = client(&server).reports().list(None).await.unwrap_err();
+
+        match err {
+            crate::Error::RateLimited { retry_after, error } => {
+                assert_eq!(retry_after, Some(std::time::Duration::from_secs(30)));
+                let error = error.expect("the error payload should be parsed");
+                assert_eq!(error.message(), Some("Slow down"));
+            }
+            other => panic!("expected a rate limit error, got {other:?}"),
+        }
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_api_error_server_error() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v2/reports");
+            then.status(500).body("<html>Internal Server Error</html>");
+        });
+
+        let err = client(&server).reports().list(None).await.unwrap_err();
+
+        match err {
+            crate::Error::Status { status, body, .. } => {
+                assert_eq!(status.as_u16(), 500);
+                assert!(body.contains("Internal Server Error"));
+            }
+            other => panic!("expected a status error, got {other:?}"),
+        }
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_list_rejects_invalid_params() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.path("/v2/jobs");
+            then.status(200)
+                .json_body_obj(&serde_json::json!({ "data": [] }));
+        });
+
+        let params = crate::QueryParams::new()
+            .limit(2000)
+            .filter(crate::Filter::condition(
+                "ocha_product.name",
+                "Flash Update",
+            ));
+        let err = client(&server)
+            .jobs()
+            .list(Some(&params))
+            .await
+            .unwrap_err();
+
+        match err {
+            crate::Error::Validation(errors) => assert_eq!(
+                errors,
+                [crate::ValidationError::LimitOutOfRange {
+                    limit: 2000,
+                    max: crate::MAX_PAGE_SIZE
+                }]
+            ),
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+        mock.assert_hits(0);
+
+        // Field names are only checked when enabled, as the API may know fields this client does not.
+        let params = params.limit(10);
+        let err = Client::builder(
+            format!("{}:{}", server.host(), server.port()).as_str(),
+            "testapp",
+        )
+        .scheme("http")
+        .validate_fields(true)
+        .build()
+        .unwrap()
+        .jobs()
+        .list_post(&params)
+        .await
+        .unwrap_err();
+        match err {
+            crate::Error::Validation(errors) => assert_eq!(
+                errors,
+                [crate::ValidationError::UnknownField {
+                    resource: "jobs".into(),
+                    field: "ocha_product.name".into(),
+                    location: "filter",
+                }]
+            ),
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+        mock.assert_hits(0);
+
+        client(&server).jobs().list(Some(&params)).await.unwrap();
+        mock.assert_hits(1);
+    }
 }
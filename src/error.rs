@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::response::ApiErrorResponse;
+
+/// A `Result` alias where the `Err` case is [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Errors returned by the ReliefWeb client.
+///
+/// # Example
+///
+/// ```no_run
+/// use reliefweb::{APIVersion, Client, Error};
+///
+/// # async fn run() {
+/// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
+/// match client.reports().get("123", None, None, None).await {
+///     Ok(resp) => println!("{:?}", resp.data[0].fields.title),
+///     Err(Error::Api { status, error, .. }) if status.as_u16() == 404 => {
+///         println!("not found: {:?}", error.message())
+///     }
+///     Err(Error::RateLimited { retry_after, .. }) => println!("slow down for {retry_after:?}"),
+///     Err(e) => eprintln!("request failed: {e}"),
+/// }
+/// # }
+/// ```
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// The API answered with a non-success HTTP status and a ReliefWeb error payload.
+    #[error("API returned HTTP {status}: {}", error.message().unwrap_or("no error message"))]
+    Api {
+        /// The HTTP status code of the response.
+        status: StatusCode,
+        /// The error payload returned by the API.
+        error: ApiErrorResponse,
+        /// How long the API asked to wait before retrying, from the `Retry-After` header.
+        retry_after: Option<Duration>,
+    },
+    /// The API answered with a non-success HTTP status and a body that is not a ReliefWeb error payload.
+    #[error("API returned HTTP {status}: {body}")]
+    Status {
+        /// The HTTP status code of the response.
+        status: StatusCode,
+        /// The raw body of the response.
+        body: String,
+        /// How long the API asked to wait before retrying, from the `Retry-After` header.
+        retry_after: Option<Duration>,
+    },
+    /// The API rejected the request because too many calls were made (HTTP 429).
+    #[error("rate limited by the API")]
+    RateLimited {
+        /// How long the API asked to wait before retrying, from the `Retry-After` header.
+        retry_after: Option<Duration>,
+        /// The error payload returned by the API, if the body is a ReliefWeb error payload.
+        error: Option<ApiErrorResponse>,
+    },
+    /// The daily quota of the client's [`RateLimiter`](crate::RateLimiter) is exhausted and it was set to [`fail_on_quota`](crate::RateLimiter::fail_on_quota), so the request was not sent.
+    #[error("daily request quota exhausted, resets in {resets_in:?}")]
+    QuotaExceeded {
+        /// Time until the daily quota resets.
+        resets_in: Duration,
+    },
+    /// The request could not be sent or its response could not be read.
+    #[error("transport error")]
+    Transport(#[from] reqwest::Error),
+    /// The response body is not valid JSON for the expected type.
+    #[error("failed to decode response at `{path}`")]
+    Decode {
+        /// Path to the offending value in the JSON document, e.g. `data[0].fields.date`.
+        path: String,
+        /// The underlying deserialization error.
+        #[source]
+        source: serde_json::Error,
+    },
+    /// The API base URL or a resource URL could not be built.
+    #[error("invalid URL")]
+    Url(#[from] url::ParseError),
+    /// The request parameters are invalid.
+    #[error("invalid parameters: {0}")]
+    InvalidParameters(String),
+    /// A date returned by the API could not be parsed by [`DocumentDates::parsed`](crate::common::DocumentDates::parsed).
+    #[error("invalid date `{value}` in `{field}`")]
+    InvalidDate {
+        /// The name of the date, e.g. `closing`.
+        field: &'static str,
+        /// The malformed date.
+        value: String,
+    },
+    /// The query parameters were rejected by [`QueryParams::validate`](crate::QueryParams::validate) before sending the request.
+    #[error("invalid query parameters: {}", join(.0))]
+    Validation(Vec<ValidationError>),
+}
+
+/// A problem found in query parameters by [`QueryParams::validate`](crate::QueryParams::validate).
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// The `limit` is over the maximum number of items the API returns.
+    #[error("limit {limit} is over the maximum of {max}")]
+    LimitOutOfRange {
+        /// The requested limit.
+        limit: u32,
+        /// The maximum limit accepted by the API.
+        max: u32,
+    },
+    /// The full-text query has no value to search for.
+    #[error("query value is empty")]
+    EmptyQuery,
+    /// A filter condition has no value to match, e.g. an empty list of values or a range without bounds.
+    #[error("filter on `{field}` has no value")]
+    EmptyFilterValue {
+        /// The field of the condition.
+        field: String,
+    },
+    /// A filter group has no conditions.
+    #[error("filter group has no conditions")]
+    EmptyFilterGroup,
+    /// A field is both included and excluded.
+    #[error("field `{field}` is both included and excluded")]
+    IncludeExcludeConflict {
+        /// The conflicting field.
+        field: String,
+    },
+    /// A field does not exist for the resource, see [`QueryParams::validate_fields_for`](crate::QueryParams::validate_fields_for).
+    #[error("unknown field `{field}` in {location} for resource `{resource}`")]
+    UnknownField {
+        /// The resource queried, e.g. `reports`.
+        resource: String,
+        /// The unknown field name.
+        field: String,
+        /// Which parameter the field was found in: `include`, `exclude`, `sort`, `query`, `filter` or `facets`.
+        location: &'static str,
+    },
+}
+
+fn join(errors: &[ValidationError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl Error {
+    /// Returns the HTTP status code associated with this error, if any.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Api { status, .. } | Error::Status { status, .. } => Some(*status),
+            Error::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            Error::Transport(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    /// Returns how long the API asked to wait before retrying, from the `Retry-After` header of the response, if any.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Error::Api { retry_after, .. }
+            | Error::Status { retry_after, .. }
+            | Error::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for Error {
+    fn from(err: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        Error::Decode {
+            path: err.path().to_string(),
+            source: err.into_inner(),
+        }
+    }
+}
@@ -3,7 +3,11 @@
 //! This module defines the structures for the "blog" endpoint in the ReliefWeb API.
 //! It includes the `BlogFields` struct and a type alias for the `ResourceEndpoint` specialized to blogs.
 
-use crate::{endpoint::ResourceEndpoint, fields::common::DocumentDates};
+use crate::{
+    endpoint::ResourceEndpoint,
+    fields::common::{DocumentDates, field_enum, string_enum},
+    resource::Resource,
+};
 use serde::{Deserialize, Serialize};
 
 /// Type alias for a `ResourceEndpoint` specialized for blogs.
@@ -17,7 +21,7 @@ pub struct BlogFields {
     /// Title of the blog.
     pub title: Option<String>,
     /// Status of the blog record.
-    pub status: Option<String>,
+    pub status: Option<BlogStatus>,
     /// Main body content of the blog.
     pub body: Option<String>,
     /// Author of the blog.
@@ -32,3 +36,32 @@ pub struct BlogFields {
     /// Various dates associated with the blog (created, changed, etc.).
     pub date: Option<DocumentDates>,
 }
+
+string_enum! {
+    /// Status of a blog record.
+    pub enum BlogStatus {
+        /// The blog post is published.
+        Published => "published",
+    }
+}
+
+field_enum! {
+    /// Names of the fields of a blog post, see [`ResourceField`](crate::ResourceField).
+    pub enum BlogField {
+        Id => "id",
+        Title => "title",
+        Status => "status",
+        Body => "body",
+        BodyHtml => "body-html",
+        Author => "author",
+        Url => "url",
+        UrlAlias => "url_alias",
+        Date => "date",
+        DateCreated => "date.created",
+        DateChanged => "date.changed",
+    }
+}
+
+impl Resource for BlogFields {
+    type Field = BlogField;
+}
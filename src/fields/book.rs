@@ -3,7 +3,11 @@
 //! This module defines the structures for the "book" endpoint in the ReliefWeb API.
 //! It includes the `BookFields` struct and a type alias for the `ResourceEndpoint` specialized to books.
 
-use crate::{common::DocumentDates, endpoint::ResourceEndpoint};
+use crate::{
+    common::{DocumentDates, field_enum, string_enum},
+    endpoint::ResourceEndpoint,
+    resource::Resource,
+};
 use serde::{Deserialize, Serialize};
 
 /// Type alias for a `ResourceEndpoint` specialized for books.
@@ -17,7 +21,7 @@ pub struct BookFields {
     /// Title of the book.
     pub title: Option<String>,
     /// Status of the book record.
-    pub status: Option<String>,
+    pub status: Option<BookStatus>,
     /// Main body content of the book.
     pub body: Option<String>,
     /// URL linking to the book record.
@@ -30,3 +34,31 @@ pub struct BookFields {
     /// Various dates associated with the book (created, changed, etc.).
     pub date: Option<DocumentDates>,
 }
+
+string_enum! {
+    /// Status of a book record.
+    pub enum BookStatus {
+        /// The book is published.
+        Published => "published",
+    }
+}
+
+field_enum! {
+    /// Names of the fields of a book, see [`ResourceField`](crate::ResourceField).
+    pub enum BookField {
+        Id => "id",
+        Title => "title",
+        Status => "status",
+        Body => "body",
+        BodyHtml => "body-html",
+        Url => "url",
+        UrlAlias => "url_alias",
+        Date => "date",
+        DateCreated => "date.created",
+        DateChanged => "date.changed",
+    }
+}
+
+impl Resource for BookFields {
+    type Field = BookField;
+}
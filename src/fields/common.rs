@@ -1,11 +1,19 @@
 //! # Shared Endpoint Fields
 //!
 //! This module defines shared structures used across multiple ReliefWeb endpoints,
-//! such as countries, locations, document dates, descriptors, languages, and sources.
+//! such as countries, cities, locations, document dates, descriptors, languages, sources, disasters,
+//! attached files and images, and country or disaster profiles.
 //!
 //! These types are typically embedded within endpoint-specific structs (e.g., `ReportFields`, `BlogFields`).
 
-use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{
+    error::{Error, Result},
+    fields::disaster::{DisasterStatus, Type},
+};
 
 /// Represents a country associated with a record.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -26,6 +34,13 @@ pub struct Country {
     pub primary: Option<bool>,
 }
 
+/// Represents a city associated with a record, such as the duty station of a job.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct City {
+    /// Name of the city.
+    pub name: Option<String>,
+}
+
 /// Represents a geographical location with latitude and longitude.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Location {
@@ -35,7 +50,11 @@ pub struct Location {
     pub lon: Option<f64>,
 }
 
-/// Represents various dates associated with a document or record.
+/// Represents various dates associated with a document or record, in ISO 8601 form (e.g. `2024-08-01T00:00:00+00:00`).
+///
+/// [`DocumentDates::parsed`] parses all the dates at once into timezone-aware datetimes of the `chrono` or `time`
+/// crates. With the `chrono` feature, accessors such as `closing_at` also parse a single date, and `is_closed` tells
+/// whether the closing date of a job or the registration deadline of a training has passed.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DocumentDates {
     /// Closing date of the document (if applicable).
@@ -46,6 +65,208 @@ pub struct DocumentDates {
     pub changed: Option<String>,
     /// Date when the document was created.
     pub created: Option<String>,
+    /// Date when the event occurred (for disasters).
+    pub event: Option<String>,
+    /// Registration deadline (for trainings).
+    pub registration: Option<String>,
+    /// Start date (for trainings).
+    pub start: Option<String>,
+    /// End date (for trainings).
+    pub end: Option<String>,
+}
+
+impl DocumentDates {
+    /// Parses all the dates into datetimes of type `T`, e.g. `chrono::DateTime<Utc>` or `time::OffsetDateTime`,
+    /// so that they are parsed once rather than on every access.
+    ///
+    /// Fails with [`Error::InvalidDate`] if a date is malformed.
+    pub fn parsed<T: ApiDateTime>(&self) -> Result<ParsedDates<T>> {
+        let parse = |field: &'static str, date: &Option<String>| {
+            date.as_deref()
+                .map(|value| {
+                    T::parse_api_date(value).ok_or_else(|| Error::InvalidDate {
+                        field,
+                        value: value.to_string(),
+                    })
+                })
+                .transpose()
+        };
+        Ok(ParsedDates {
+            closing: parse("closing", &self.closing)?,
+            original: parse("original", &self.original)?,
+            changed: parse("changed", &self.changed)?,
+            created: parse("created", &self.created)?,
+            event: parse("event", &self.event)?,
+            registration: parse("registration", &self.registration)?,
+            start: parse("start", &self.start)?,
+            end: parse("end", &self.end)?,
+        })
+    }
+}
+
+/// The dates of a [`DocumentDates`] parsed into datetimes of type `T`, as returned by [`DocumentDates::parsed`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedDates<T> {
+    /// Closing date of the document (if applicable).
+    pub closing: Option<T>,
+    /// Original date of the document.
+    pub original: Option<T>,
+    /// Date when the document was last changed.
+    pub changed: Option<T>,
+    /// Date when the document was created.
+    pub created: Option<T>,
+    /// Date when the event occurred (for disasters).
+    pub event: Option<T>,
+    /// Registration deadline (for trainings).
+    pub registration: Option<T>,
+    /// Start date (for trainings).
+    pub start: Option<T>,
+    /// End date (for trainings).
+    pub end: Option<T>,
+}
+
+/// A datetime type the dates of [`DocumentDates`] can be parsed into with [`DocumentDates::parsed`].
+///
+/// With the `chrono` or `time` features, this is implemented for `chrono::DateTime<Utc>`, `chrono::DateTime<FixedOffset>`
+/// and `time::OffsetDateTime`. Dates are parsed as RFC 3339 datetimes, or as dates alone taken at midnight UTC.
+pub trait ApiDateTime: Sized {
+    /// Parses a date returned by the API, or returns `None` if it is malformed.
+    fn parse_api_date(date: &str) -> Option<Self>;
+}
+
+#[cfg(feature = "chrono")]
+impl ApiDateTime for chrono::DateTime<chrono::FixedOffset> {
+    fn parse_api_date(date: &str) -> Option<Self> {
+        parse_date(date)?.ok()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ApiDateTime for chrono::DateTime<chrono::Utc> {
+    fn parse_api_date(date: &str) -> Option<Self> {
+        chrono::DateTime::<chrono::FixedOffset>::parse_api_date(date)
+            .map(|date| date.with_timezone(&chrono::Utc))
+    }
+}
+
+#[cfg(feature = "time")]
+impl ApiDateTime for time::OffsetDateTime {
+    fn parse_api_date(date: &str) -> Option<Self> {
+        time::OffsetDateTime::parse(date, &time::format_description::well_known::Rfc3339)
+            .ok()
+            .or_else(|| {
+                time::Date::parse(
+                    date,
+                    time::macros::format_description!("[year]-[month]-[day]"),
+                )
+                .ok()
+                .map(|date| date.midnight().assume_utc())
+            })
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl DocumentDates {
+    /// Returns the closing date, parsed.
+    pub fn closing_at(&self) -> Option<chrono::ParseResult<chrono::DateTime<chrono::FixedOffset>>> {
+        parse_date(self.closing.as_deref()?)
+    }
+
+    /// Returns the original date, parsed.
+    pub fn original_at(
+        &self,
+    ) -> Option<chrono::ParseResult<chrono::DateTime<chrono::FixedOffset>>> {
+        parse_date(self.original.as_deref()?)
+    }
+
+    /// Returns the date of the last change, parsed.
+    pub fn changed_at(&self) -> Option<chrono::ParseResult<chrono::DateTime<chrono::FixedOffset>>> {
+        parse_date(self.changed.as_deref()?)
+    }
+
+    /// Returns the creation date, parsed.
+    pub fn created_at(&self) -> Option<chrono::ParseResult<chrono::DateTime<chrono::FixedOffset>>> {
+        parse_date(self.created.as_deref()?)
+    }
+
+    /// Returns the event date, parsed.
+    pub fn event_at(&self) -> Option<chrono::ParseResult<chrono::DateTime<chrono::FixedOffset>>> {
+        parse_date(self.event.as_deref()?)
+    }
+
+    /// Returns the registration deadline, parsed.
+    pub fn registration_at(
+        &self,
+    ) -> Option<chrono::ParseResult<chrono::DateTime<chrono::FixedOffset>>> {
+        parse_date(self.registration.as_deref()?)
+    }
+
+    /// Returns the start date, parsed.
+    pub fn start_at(&self) -> Option<chrono::ParseResult<chrono::DateTime<chrono::FixedOffset>>> {
+        parse_date(self.start.as_deref()?)
+    }
+
+    /// Returns the end date, parsed.
+    pub fn end_at(&self) -> Option<chrono::ParseResult<chrono::DateTime<chrono::FixedOffset>>> {
+        parse_date(self.end.as_deref()?)
+    }
+
+    /// Returns `true` if the closing date has passed, e.g. for a job no longer accepting applications.
+    pub fn is_closed(&self) -> bool {
+        self.is_closed_at(&chrono::Utc::now())
+    }
+
+    /// Returns `true` if the closing date is before `now`.
+    ///
+    /// A closing date at midnight, as the API returns dates without a time of day, lasts until the end of that day.
+    /// A missing or malformed closing date is never considered passed.
+    pub fn is_closed_at<Tz: chrono::TimeZone>(&self, now: &chrono::DateTime<Tz>) -> bool {
+        is_passed(self.closing_at(), now)
+    }
+
+    /// Returns `true` if the registration deadline has passed, e.g. for a training no longer accepting participants.
+    pub fn is_registration_closed(&self) -> bool {
+        self.is_registration_closed_at(&chrono::Utc::now())
+    }
+
+    /// Returns `true` if the registration deadline is before `now`, with the same rules as [`is_closed_at`](Self::is_closed_at).
+    pub fn is_registration_closed_at<Tz: chrono::TimeZone>(
+        &self,
+        now: &chrono::DateTime<Tz>,
+    ) -> bool {
+        is_passed(self.registration_at(), now)
+    }
+}
+
+/// Parses a date returned by the API, either as an RFC 3339 datetime or as a date alone, taken at midnight UTC.
+#[cfg(feature = "chrono")]
+fn parse_date(date: &str) -> Option<chrono::ParseResult<chrono::DateTime<chrono::FixedOffset>>> {
+    Some(chrono::DateTime::parse_from_rfc3339(date).or_else(|err| {
+        chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map(|date| {
+                date.and_time(chrono::NaiveTime::MIN)
+                    .and_utc()
+                    .fixed_offset()
+            })
+            .map_err(|_| err)
+    }))
+}
+
+/// Returns `true` if the deadline is before `now`, a deadline at midnight lasting until the end of its day.
+#[cfg(feature = "chrono")]
+fn is_passed<Tz: chrono::TimeZone>(
+    deadline: Option<chrono::ParseResult<chrono::DateTime<chrono::FixedOffset>>>,
+    now: &chrono::DateTime<Tz>,
+) -> bool {
+    let Some(Ok(deadline)) = deadline else {
+        return false;
+    };
+    let deadline = if deadline.time() == chrono::NaiveTime::MIN {
+        deadline + chrono::Days::new(1)
+    } else {
+        deadline
+    };
+    deadline <= *now
 }
 
 /// Represents a generic descriptor, used for types like source types.
@@ -89,3 +310,275 @@ pub struct Source {
     #[serde(rename = "type")]
     pub source_type: Option<Descriptor>,
 }
+
+/// Represents a disaster associated with a record.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Disaster {
+    /// Link to the API resource for this disaster.
+    pub href: Option<String>,
+    /// The unique identifier of the disaster.
+    pub id: Option<i64>,
+    /// Name of the disaster.
+    pub name: Option<String>,
+    /// GLIDE identifier of the disaster.
+    pub glide: Option<String>,
+    /// Status of the disaster record.
+    pub status: Option<DisasterStatus>,
+    /// Types of the disaster.
+    #[serde(rename = "type")]
+    pub disaster_type: Option<Vec<Type>>,
+}
+
+/// Represents a file attached to a record, such as the PDF version of a report.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct File {
+    /// The unique identifier of the file.
+    #[serde(default, deserialize_with = "lenient_number")]
+    pub id: Option<i64>,
+    /// Description of the file.
+    pub description: Option<String>,
+    /// URL to download the file.
+    pub url: Option<String>,
+    /// Name of the file.
+    pub filename: Option<String>,
+    /// MIME type of the file (e.g., `application/pdf`).
+    pub mimetype: Option<String>,
+    /// Size of the file in bytes.
+    #[serde(default, deserialize_with = "lenient_number")]
+    pub filesize: Option<u64>,
+    /// Preview image of the first page of the file, if any.
+    pub preview: Option<FilePreview>,
+}
+
+/// Represents the preview images generated for an attached file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FilePreview {
+    /// URL of the preview at its original size.
+    pub url: Option<String>,
+    /// URL of the large preview.
+    pub url_large: Option<String>,
+    /// URL of the small preview.
+    pub url_small: Option<String>,
+    /// URL of the thumbnail preview.
+    pub url_thumb: Option<String>,
+    /// Version of the preview.
+    pub version: Option<String>,
+}
+
+/// Represents an image attached to a record, such as a report illustration or a source logo.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Image {
+    /// The unique identifier of the image.
+    #[serde(default, deserialize_with = "lenient_number")]
+    pub id: Option<i64>,
+    /// Width of the image in pixels.
+    #[serde(default, deserialize_with = "lenient_number")]
+    pub width: Option<u32>,
+    /// Height of the image in pixels.
+    #[serde(default, deserialize_with = "lenient_number")]
+    pub height: Option<u32>,
+    /// URL of the image at its original size.
+    pub url: Option<String>,
+    /// URL of the large version of the image.
+    pub url_large: Option<String>,
+    /// URL of the small version of the image.
+    pub url_small: Option<String>,
+    /// URL of the thumbnail version of the image.
+    pub url_thumb: Option<String>,
+    /// Caption of the image.
+    pub caption: Option<String>,
+    /// Copyright notice of the image.
+    pub copyright: Option<String>,
+    /// Name of the image file.
+    pub filename: Option<String>,
+    /// MIME type of the image (e.g., `image/jpeg`).
+    pub mimetype: Option<String>,
+    /// Size of the image file in bytes.
+    #[serde(default, deserialize_with = "lenient_number")]
+    pub filesize: Option<u64>,
+}
+
+/// Represents the profile of a country or a disaster, including an overview and curated links.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Profile {
+    /// Plain text overview.
+    pub overview: Option<String>,
+    /// HTML-formatted overview.
+    pub overview_html: Option<String>,
+    /// Key documents, such as situation reports or assessments.
+    #[serde(rename = "key_content")]
+    pub key_content: Option<Vec<ProfileLink>>,
+    /// Appeals and response plans.
+    #[serde(rename = "appeals_response_plans")]
+    pub appeals_response_plans: Option<Vec<ProfileLink>>,
+    /// Useful links to other websites.
+    #[serde(rename = "useful_links")]
+    pub useful_links: Option<Vec<ProfileLink>>,
+}
+
+/// Represents a link listed in a [`Profile`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProfileLink {
+    /// Title of the link.
+    pub title: Option<String>,
+    /// URL of the link.
+    pub url: Option<String>,
+    /// URL of the logo or cover image of the link.
+    pub logo: Option<String>,
+}
+
+/// Defines an enum of the string values used by the API for a field, with an `Unknown` fallback
+/// keeping values this client does not know about.
+///
+/// The enum is (de)serialized from and to its string form, and implements `Display` and `From<String>`.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        #[serde(from = "String", into = "String")]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value not known to this client.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// Returns the value as used by the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(s) => s,
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(s: String) -> Self {
+                match s.as_str() {
+                    $($value => $name::$variant,)*
+                    _ => $name::Unknown(s),
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::Unknown(s) => s,
+                    other => other.as_str().to_string(),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;
+
+/// Defines the enum of the field names of a resource, as listed in the
+/// [field tables](https://apidoc.reliefweb.int/fields-tables).
+///
+/// The enum converts into the `String` expected by [`QueryParams`](crate::QueryParams) and the other query types,
+/// so that a field name can be given either as a variant or as a raw string.
+macro_rules! field_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident => $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                #[doc = concat!("`", $value, "`")]
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// All the fields of the resource.
+            pub const ALL: &'static [$name] = &[$($name::$variant,)*];
+
+            /// Returns the field name as used by the API.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $value,)*
+                }
+            }
+
+            /// Returns the field with the given API name, if it exists for the resource.
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($value => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(field: $name) -> Self {
+                field.as_str().to_string()
+            }
+        }
+
+        impl $crate::resource::ResourceField for $name {
+            fn as_str(&self) -> &'static str {
+                $name::as_str(self)
+            }
+
+            fn from_name(name: &str) -> Option<Self> {
+                $name::from_name(name)
+            }
+        }
+    };
+}
+
+pub(crate) use field_enum;
+
+/// Deserializes an optional number that the API returns either as a number or as a numeric string.
+pub(crate) fn lenient_number<'de, D, N>(deserializer: D) -> Result<Option<N>, D::Error>
+where
+    D: Deserializer<'de>,
+    N: FromStr,
+    N::Err: std::fmt::Display,
+{
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Number(n)) => n
+            .to_string()
+            .parse()
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        Some(serde_json::Value::String(s)) if s.is_empty() => Ok(None),
+        Some(serde_json::Value::String(s)) => s.parse().map(Some).map_err(serde::de::Error::custom),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "expected a number or a numeric string, got {other}"
+        ))),
+    }
+}
@@ -7,21 +7,22 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     endpoint::ResourceEndpoint,
-    fields::common::{DocumentDates, Location},
+    fields::common::{DocumentDates, Location, Profile, field_enum, string_enum},
+    resource::Resource,
 };
 
 /// Type alias for a `ResourceEndpoint` specialized for countries.
 pub type CountriesEndpoint<'c> = ResourceEndpoint<'c, CountryFields>;
 
 /// Represents the fields of a country record returned by the ReliefWeb API.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CountryFields {
     /// The unique identifier of the country.
     pub id: Option<i64>,
     /// Full country name.
     pub name: Option<String>,
     /// Status of the country record (if available).
-    pub status: Option<String>,
+    pub status: Option<CountryStatus>,
     /// Short name or abbreviation of the country.
     pub shortname: Option<String>,
     /// ISO 3166-1 alpha-3 code for the country.
@@ -34,4 +35,67 @@ pub struct CountryFields {
     pub date: Option<DocumentDates>,
     /// Geographical location (latitude and longitude) of the country.
     pub location: Option<Location>,
+    /// Short description of the country.
+    pub description: Option<String>,
+    /// HTML-formatted description of the country.
+    #[serde(rename = "description-html")]
+    pub description_html: Option<String>,
+    /// Optional profile information (overview, key content, useful links) of the country.
+    pub profile: Option<Profile>,
+    /// Whether the country is affected by a current humanitarian situation.
+    pub current: Option<bool>,
+    /// Whether the country is featured on ReliefWeb.
+    pub featured: Option<bool>,
+}
+
+string_enum! {
+    /// Status of a country record.
+    pub enum CountryStatus {
+        /// The country is affected by a current humanitarian situation.
+        Current => "current",
+        /// The country is not the focus of a current humanitarian situation.
+        Normal => "normal",
+    }
+}
+
+field_enum! {
+    /// Names of the fields of a country, see [`ResourceField`](crate::ResourceField).
+    pub enum CountryField {
+        Id => "id",
+        Name => "name",
+        Shortname => "shortname",
+        Iso3 => "iso3",
+        Status => "status",
+        Current => "current",
+        Featured => "featured",
+        Description => "description",
+        DescriptionHtml => "description-html",
+        Location => "location",
+        LocationLat => "location.lat",
+        LocationLon => "location.lon",
+        Url => "url",
+        UrlAlias => "url_alias",
+        Profile => "profile",
+        ProfileOverview => "profile.overview",
+        ProfileOverviewHtml => "profile.overview-html",
+        ProfileKeyContent => "profile.key_content",
+        ProfileKeyContentLogo => "profile.key_content.logo",
+        ProfileKeyContentTitle => "profile.key_content.title",
+        ProfileKeyContentUrl => "profile.key_content.url",
+        ProfileAppealsResponsePlans => "profile.appeals_response_plans",
+        ProfileAppealsResponsePlansLogo => "profile.appeals_response_plans.logo",
+        ProfileAppealsResponsePlansTitle => "profile.appeals_response_plans.title",
+        ProfileAppealsResponsePlansUrl => "profile.appeals_response_plans.url",
+        ProfileUsefulLinks => "profile.useful_links",
+        ProfileUsefulLinksLogo => "profile.useful_links.logo",
+        ProfileUsefulLinksTitle => "profile.useful_links.title",
+        ProfileUsefulLinksUrl => "profile.useful_links.url",
+        Date => "date",
+        DateCreated => "date.created",
+        DateChanged => "date.changed",
+    }
+}
+
+impl Resource for CountryFields {
+    type Field = CountryField;
 }
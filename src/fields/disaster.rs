@@ -7,14 +7,17 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     endpoint::ResourceEndpoint,
-    fields::common::{Country, DocumentDates},
+    fields::common::{Country, DocumentDates, field_enum, string_enum},
+    resource::{Archivable, Resource},
 };
 
+pub use crate::fields::common::Profile;
+
 /// Type alias for a `ResourceEndpoint` specialized for disasters.
 pub type DisastersEndpoint<'c> = ResourceEndpoint<'c, DisasterFields>;
 
 /// Represents the fields of a disaster record returned by the ReliefWeb API.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DisasterFields {
     /// Unique identifier of the disaster.
     pub id: Option<i64>,
@@ -23,7 +26,7 @@ pub struct DisasterFields {
     /// Short description of the disaster.
     pub description: Option<String>,
     /// Status of the disaster record.
-    pub status: Option<String>,
+    pub status: Option<DisasterStatus>,
     /// GLIDE identifier for the disaster.
     pub glide: Option<String>,
     /// The primary country affected by the disaster.
@@ -44,12 +47,18 @@ pub struct DisasterFields {
     /// HTML-formatted description of the disaster.
     #[serde(rename = "description-html")]
     pub description_html: Option<String>,
-    /// Optional profile information (overview) of the disaster.
+    /// Optional profile information (overview, key content, useful links) of the disaster.
     pub profile: Option<Profile>,
+    /// Whether the disaster is currently ongoing.
+    pub current: Option<bool>,
+    /// Whether the disaster is featured on ReliefWeb.
+    pub featured: Option<bool>,
+    /// GLIDE identifiers of related disasters.
+    pub related_glide: Option<Vec<String>>,
 }
 
 /// Represents a disaster type.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Type {
     /// Unique identifier of the type.
     pub id: Option<i64>,
@@ -61,12 +70,88 @@ pub struct Type {
     pub primary: Option<bool>,
 }
 
-/// Represents an optional profile for a disaster, including HTML overview.
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub struct Profile {
-    /// Plain text overview of the disaster.
-    pub overview: Option<String>,
-    /// HTML-formatted overview.
-    pub overview_html: Option<String>,
+string_enum! {
+    /// Status of a disaster record, following the lifecycle of the disaster.
+    pub enum DisasterStatus {
+        /// The disaster is expected or just started, e.g. an approaching cyclone.
+        Alert => "alert",
+        /// The disaster is ongoing.
+        Ongoing => "ongoing",
+        /// The disaster is current, i.e. still followed by ReliefWeb.
+        Current => "current",
+        /// The disaster is over.
+        Past => "past",
+    }
+}
+
+field_enum! {
+    /// Names of the fields of a disaster, see [`ResourceField`](crate::ResourceField).
+    pub enum DisasterField {
+        Id => "id",
+        Name => "name",
+        Description => "description",
+        DescriptionHtml => "description-html",
+        Status => "status",
+        Glide => "glide",
+        RelatedGlide => "related_glide",
+        Current => "current",
+        Featured => "featured",
+        Url => "url",
+        UrlAlias => "url_alias",
+        PrimaryCountry => "primary_country",
+        PrimaryCountryId => "primary_country.id",
+        PrimaryCountryName => "primary_country.name",
+        PrimaryCountryShortname => "primary_country.shortname",
+        PrimaryCountryIso3 => "primary_country.iso3",
+        PrimaryCountryLocation => "primary_country.location",
+        PrimaryCountryLocationLat => "primary_country.location.lat",
+        PrimaryCountryLocationLon => "primary_country.location.lon",
+        PrimaryCountryHref => "primary_country.href",
+        PrimaryCountryPrimary => "primary_country.primary",
+        PrimaryType => "primary_type",
+        PrimaryTypeId => "primary_type.id",
+        PrimaryTypeName => "primary_type.name",
+        PrimaryTypeCode => "primary_type.code",
+        PrimaryTypePrimary => "primary_type.primary",
+        Country => "country",
+        CountryId => "country.id",
+        CountryName => "country.name",
+        CountryShortname => "country.shortname",
+        CountryIso3 => "country.iso3",
+        CountryLocation => "country.location",
+        CountryLocationLat => "country.location.lat",
+        CountryLocationLon => "country.location.lon",
+        CountryPrimary => "country.primary",
+        CountryHref => "country.href",
+        Type => "type",
+        TypeId => "type.id",
+        TypeName => "type.name",
+        TypeCode => "type.code",
+        TypePrimary => "type.primary",
+        Profile => "profile",
+        ProfileOverview => "profile.overview",
+        ProfileOverviewHtml => "profile.overview-html",
+        ProfileKeyContent => "profile.key_content",
+        ProfileKeyContentLogo => "profile.key_content.logo",
+        ProfileKeyContentTitle => "profile.key_content.title",
+        ProfileKeyContentUrl => "profile.key_content.url",
+        ProfileAppealsResponsePlans => "profile.appeals_response_plans",
+        ProfileAppealsResponsePlansLogo => "profile.appeals_response_plans.logo",
+        ProfileAppealsResponsePlansTitle => "profile.appeals_response_plans.title",
+        ProfileAppealsResponsePlansUrl => "profile.appeals_response_plans.url",
+        ProfileUsefulLinks => "profile.useful_links",
+        ProfileUsefulLinksLogo => "profile.useful_links.logo",
+        ProfileUsefulLinksTitle => "profile.useful_links.title",
+        ProfileUsefulLinksUrl => "profile.useful_links.url",
+        Date => "date",
+        DateCreated => "date.created",
+        DateChanged => "date.changed",
+        DateEvent => "date.event",
+    }
 }
+
+impl Resource for DisasterFields {
+    type Field = DisasterField;
+}
+
+impl Archivable for DisasterFields {}
@@ -7,25 +7,33 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     endpoint::ResourceEndpoint,
-    fields::common::{Descriptor, DocumentDates, Source},
+    fields::common::{City, Country, Descriptor, DocumentDates, Source, field_enum, string_enum},
+    resource::{Archivable, Resource},
 };
 
 /// Type alias for a `ResourceEndpoint` specialized for jobs.
 pub type JobsEndpoint<'c> = ResourceEndpoint<'c, JobFields>;
 
 /// Represents the fields of a job record returned by the ReliefWeb API.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct JobFields {
     /// Unique identifier of the job.
     pub id: Option<i64>,
     /// Title of the job.
     pub title: Option<String>,
     /// Status of the job record.
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
     /// Job description.
     pub body: Option<String>,
     /// Instructions on how to apply for the job.
     pub how_to_apply: Option<String>,
+    /// HTML-formatted instructions on how to apply for the job.
+    #[serde(rename = "how_to_apply-html")]
+    pub how_to_apply_html: Option<String>,
+    /// Countries where the job is based.
+    pub country: Option<Vec<Country>>,
+    /// Cities where the job is based.
+    pub city: Option<Vec<City>>,
     /// Sources associated with the job posting.
     pub source: Option<Vec<Source>>,
     /// Themes associated with the job.
@@ -44,6 +52,86 @@ pub struct JobFields {
     /// HTML-formatted job description.
     #[serde(rename = "body-html")]
     pub body_html: Option<String>,
-    /// Various dates associated with the job record (created, changed, closing, etc.).
+    /// Various dates associated with the job record (created, changed, etc.), including the application deadline in `closing`.
     pub date: Option<DocumentDates>,
 }
+
+#[cfg(feature = "chrono")]
+impl JobFields {
+    /// Returns `true` if the job no longer accepts applications, i.e. its closing date has passed.
+    pub fn is_closed(&self) -> bool {
+        self.date.as_ref().is_some_and(DocumentDates::is_closed)
+    }
+}
+
+string_enum! {
+    /// Status of a job record.
+    pub enum JobStatus {
+        /// The job is published and accepts applications.
+        Published => "published",
+        /// The job reached its closing date.
+        Expired => "expired",
+        /// The job awaits review by the editors.
+        ToReview => "to-review",
+    }
+}
+
+field_enum! {
+    /// Names of the fields of a job, see [`ResourceField`](crate::ResourceField).
+    pub enum JobField {
+        Id => "id",
+        Title => "title",
+        Status => "status",
+        Body => "body",
+        BodyHtml => "body-html",
+        HowToApply => "how_to_apply",
+        HowToApplyHtml => "how_to_apply-html",
+        Url => "url",
+        UrlAlias => "url_alias",
+        Source => "source",
+        SourceId => "source.id",
+        SourceName => "source.name",
+        SourceShortname => "source.shortname",
+        SourceLongname => "source.longname",
+        SourceSpanishName => "source.spanish_name",
+        SourceHomepage => "source.homepage",
+        SourceType => "source.type",
+        SourceTypeId => "source.type.id",
+        SourceTypeName => "source.type.name",
+        SourceHref => "source.href",
+        Country => "country",
+        CountryId => "country.id",
+        CountryName => "country.name",
+        CountryShortname => "country.shortname",
+        CountryIso3 => "country.iso3",
+        CountryLocation => "country.location",
+        CountryLocationLat => "country.location.lat",
+        CountryLocationLon => "country.location.lon",
+        CountryHref => "country.href",
+        CountryPrimary => "country.primary",
+        City => "city",
+        CityName => "city.name",
+        Theme => "theme",
+        ThemeId => "theme.id",
+        ThemeName => "theme.name",
+        Type => "type",
+        TypeId => "type.id",
+        TypeName => "type.name",
+        Experience => "experience",
+        ExperienceId => "experience.id",
+        ExperienceName => "experience.name",
+        CareerCategories => "career_categories",
+        CareerCategoriesId => "career_categories.id",
+        CareerCategoriesName => "career_categories.name",
+        Date => "date",
+        DateCreated => "date.created",
+        DateChanged => "date.changed",
+        DateClosing => "date.closing",
+    }
+}
+
+impl Resource for JobFields {
+    type Field = JobField;
+}
+
+impl Archivable for JobFields {}
@@ -13,6 +13,7 @@
 //! - `country` – Fields for countries.
 //! - `disaster` – Fields for disaster events.
 //! - `job` – Fields for job postings.
+//! - `reference` – Fields for reference vocabularies (themes, formats, languages, etc.).
 //! - `report` – Fields for reports.
 //! - `source` – Fields for sources of information.
 //! - `training` – Fields for training resources.
@@ -24,6 +25,7 @@ pub mod book;
 pub mod country;
 pub mod disaster;
 pub mod job;
+pub mod reference;
 pub mod report;
 pub mod source;
 pub mod training;
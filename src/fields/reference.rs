@@ -0,0 +1,327 @@
+//! # Reference Endpoint Fields
+//!
+//! This module defines the structures for the "references" endpoints in the ReliefWeb API.
+//! It includes the `ReferenceFields` struct, a type alias for the `ResourceEndpoint` specialized to references,
+//! the [`References`] accessor to each vocabulary, and the [`Vocabulary`] lookup table.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+
+use crate::{
+    Client,
+    endpoint::ResourceEndpoint,
+    error::Result,
+    fields::common::field_enum,
+    pagination::{MAX_PAGE_SIZE, PaginationOptions},
+    params::{QueryParams, QueryProfile},
+    resource::Resource,
+};
+
+/// Vocabularies fetched by a client, by resource. Each vocabulary is fetched at most once, even by concurrent calls.
+pub(crate) type VocabularyCache = Mutex<HashMap<&'static str, Arc<OnceCell<Arc<Vocabulary>>>>>;
+
+/// Type alias for a `ResourceEndpoint` specialized for reference vocabularies.
+pub type ReferencesEndpoint<'c> = ResourceEndpoint<'c, ReferenceFields>;
+
+/// Represents the fields of a vocabulary term returned by the ReliefWeb API.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReferenceFields {
+    /// Unique identifier of the term.
+    pub id: Option<i64>,
+    /// Name of the term.
+    pub name: Option<String>,
+    /// Short name of the term.
+    pub shortname: Option<String>,
+    /// Code of the term, such as the ISO code of a language.
+    pub code: Option<String>,
+    /// Description of the term.
+    pub description: Option<String>,
+}
+
+/// Accessor to the reference vocabularies of the API, returned by [`Client::references`].
+///
+/// # Example
+///
+/// ```no_run
+/// use reliefweb::{Client, APIVersion};
+///
+/// # async fn run() {
+/// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
+/// let themes = client.references().themes().vocabulary().await.unwrap();
+/// println!("{:?}", themes.label(4590));
+/// # }
+/// ```
+pub struct References<'c> {
+    client: &'c Client,
+}
+
+impl<'c> References<'c> {
+    pub(crate) fn new(client: &'c Client) -> Self {
+        Self { client }
+    }
+
+    /// Returns the endpoint of the career categories of jobs.
+    pub fn career_categories(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/career-categories")
+    }
+
+    /// Returns the endpoint of the disaster types.
+    pub fn disaster_types(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/disaster-types")
+    }
+
+    /// Returns the endpoint of the experience levels required by jobs.
+    pub fn job_experience(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/job-experience")
+    }
+
+    /// Returns the endpoint of the job types.
+    pub fn job_types(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/job-types")
+    }
+
+    /// Returns the endpoint of the languages.
+    pub fn languages(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/languages")
+    }
+
+    /// Returns the endpoint of the OCHA products.
+    pub fn ocha_products(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/ocha-products")
+    }
+
+    /// Returns the endpoint of the organization types of sources.
+    pub fn organization_types(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/organization-types")
+    }
+
+    /// Returns the endpoint of the report formats.
+    pub fn report_formats(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/content-formats")
+    }
+
+    /// Returns the endpoint of the themes.
+    pub fn themes(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/themes")
+    }
+
+    /// Returns the endpoint of the training formats.
+    pub fn training_formats(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/training-formats")
+    }
+
+    /// Returns the endpoint of the training types.
+    pub fn training_types(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/training-types")
+    }
+
+    /// Returns the endpoint of the vulnerable groups.
+    pub fn vulnerable_groups(&self) -> ReferencesEndpoint<'c> {
+        ReferencesEndpoint::new(self.client, "references/vulnerable-groups")
+    }
+}
+
+/// An in-memory lookup table of the terms of a vocabulary, by id and by name.
+#[derive(Clone, Debug, Default)]
+pub struct Vocabulary {
+    terms: Vec<ReferenceFields>,
+    by_id: HashMap<i64, usize>,
+    by_name: HashMap<String, usize>,
+}
+
+impl Vocabulary {
+    /// Create a lookup table from the given terms.
+    pub fn new(terms: Vec<ReferenceFields>) -> Self {
+        let mut by_id = HashMap::new();
+        let mut by_name = HashMap::new();
+        for (i, term) in terms.iter().enumerate() {
+            if let Some(id) = term.id {
+                by_id.insert(id, i);
+            }
+            if let Some(name) = &term.name {
+                by_name.insert(name.to_lowercase(), i);
+            }
+        }
+        Self {
+            terms,
+            by_id,
+            by_name,
+        }
+    }
+
+    /// Returns the term with the given id.
+    pub fn get(&self, id: i64) -> Option<&ReferenceFields> {
+        self.by_id.get(&id).map(|&i| &self.terms[i])
+    }
+
+    /// Returns the term with the given name, ignoring case.
+    pub fn find(&self, name: &str) -> Option<&ReferenceFields> {
+        self.by_name
+            .get(&name.to_lowercase())
+            .map(|&i| &self.terms[i])
+    }
+
+    /// Returns the name of the term with the given id.
+    pub fn label(&self, id: i64) -> Option<&str> {
+        self.get(id)?.name.as_deref()
+    }
+
+    /// Returns all the terms of the vocabulary.
+    pub fn terms(&self) -> &[ReferenceFields] {
+        &self.terms
+    }
+
+    /// Returns the number of terms in the vocabulary.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns `true` if the vocabulary has no term.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<'c> ResourceEndpoint<'c, ReferenceFields> {
+    /// Returns all the terms of this vocabulary as a lookup table.
+    ///
+    /// The vocabulary is fetched page by page on first use, then cached by the client for subsequent calls.
+    /// Concurrent first calls share a single fetch. A failed fetch is not cached, so the next call tries again.
+    pub async fn vocabulary(&self) -> Result<Arc<Vocabulary>> {
+        let cell = self
+            .client
+            .inner
+            .references_cache
+            .lock()
+            .unwrap()
+            .entry(self.resource)
+            .or_default()
+            .clone();
+        cell.get_or_try_init(|| async {
+            let params = QueryParams::new().profile(QueryProfile::Full);
+            let terms = self
+                .list_stream(params, PaginationOptions::new().page_size(MAX_PAGE_SIZE))
+                .map_ok(|item| item.fields)
+                .try_collect()
+                .await?;
+            Ok(Arc::new(Vocabulary::new(terms)))
+        })
+        .await
+        .cloned()
+    }
+}
+
+field_enum! {
+    /// Names of the fields of a vocabulary term, see [`ResourceField`](crate::ResourceField).
+    pub enum ReferenceField {
+        Id => "id",
+        Name => "name",
+        Shortname => "shortname",
+        Code => "code",
+        Description => "description",
+    }
+}
+
+impl Resource for ReferenceFields {
+    type Field = ReferenceField;
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use crate::Client;
+
+    #[tokio::test]
+    async fn vocabulary_is_cached() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v2/references/themes")
+                .query_param("limit", "1000");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": [
+                    { "id": "4590", "fields": { "id": 4590, "name": "Health" } },
+                    { "id": "4591", "fields": { "id": 4591, "name": "Protection and Human Rights" } }
+                ]
+            }));
+        });
+
+        let client = Client::new_with_scheme(
+            "http",
+            format!("{}:{}", server.host(), server.port()).as_str(),
+            "testapp",
+            crate::APIVersion::V2,
+        )
+        .unwrap();
+
+        let themes = client.references().themes().vocabulary().await.unwrap();
+        assert_eq!(themes.len(), 2);
+        assert_eq!(themes.label(4590), Some("Health"));
+        assert_eq!(
+            themes
+                .find("protection and human rights")
+                .and_then(|t| t.id),
+            Some(4591)
+        );
+        assert!(themes.get(1).is_none());
+
+        let again = client.references().themes().vocabulary().await.unwrap();
+        assert_eq!(again.len(), 2);
+
+        let themes = client.clone().references().themes().into_owned();
+        drop(client);
+        let shared = tokio::spawn(async move { themes.vocabulary().await })
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(shared.len(), 2);
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn vocabulary_is_fetched_in_full_once() {
+        let server = MockServer::start();
+        let term = |i: usize| serde_json::json!({ "id": i.to_string(), "fields": { "id": i, "name": format!("Term {i}") } });
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v2/references/languages")
+                .query_param("offset", "0");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "totalCount": 1001,
+                "links": { "next": { "href": "http://example.com/next" } },
+                "data": (0..1000).map(term).collect::<Vec<_>>()
+            }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v2/references/languages")
+                .query_param("offset", "1000");
+            then.status(200).json_body_obj(&serde_json::json!({
+                "totalCount": 1001,
+                "data": [term(1000)]
+            }));
+        });
+
+        let client = Client::new_with_scheme(
+            "http",
+            format!("{}:{}", server.host(), server.port()).as_str(),
+            "testapp",
+            crate::APIVersion::V2,
+        )
+        .unwrap();
+
+        let languages = client.references().languages();
+        let (a, b) = tokio::join!(languages.vocabulary(), languages.vocabulary());
+        assert_eq!(a.unwrap().len(), 1001);
+        assert_eq!(b.unwrap().label(1000), Some("Term 1000"));
+        first.assert_hits(1);
+        second.assert_hits(1);
+    }
+}
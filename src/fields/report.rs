@@ -7,7 +7,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     endpoint::ResourceEndpoint,
-    fields::common::{Country, Descriptor, DocumentDates, Language, Source},
+    fields::{
+        common::{
+            Country, Descriptor, Disaster, DocumentDates, File, Image, Language, Source,
+            field_enum, string_enum,
+        },
+        disaster::Type,
+    },
+    resource::Resource,
 };
 
 /// Type alias for a `ResourceEndpoint` specialized for reports.
@@ -21,7 +28,7 @@ pub struct ReportFields {
     /// Title of the report.
     pub title: Option<String>,
     /// Status of the report record.
-    pub status: Option<String>,
+    pub status: Option<ReportStatus>,
     /// Report description or body.
     pub body: Option<String>,
     /// Origin of the report.
@@ -47,4 +54,175 @@ pub struct ReportFields {
     pub body_html: Option<String>,
     /// Various dates associated with the report (created, changed, closing, etc.).
     pub date: Option<DocumentDates>,
+    /// Files attached to the report, such as its PDF version.
+    pub file: Option<Vec<File>>,
+    /// Illustration of the report.
+    pub image: Option<Image>,
+    /// Headline of the report, for reports featured on the ReliefWeb homepage.
+    pub headline: Option<Headline>,
+    /// Disasters covered by the report.
+    pub disaster: Option<Vec<Disaster>>,
+    /// Types of the disasters covered by the report.
+    pub disaster_type: Option<Vec<Type>>,
+    /// Population groups the report focuses on.
+    pub vulnerable_groups: Option<Vec<Descriptor>>,
+    /// OCHA product the report belongs to (e.g., situation report, humanitarian bulletin).
+    pub ocha_product: Option<Vec<Descriptor>>,
+    /// Editorial features the report is part of.
+    pub feature: Option<Vec<Descriptor>>,
+}
+
+/// Represents the headline of a featured report.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Headline {
+    /// Title of the headline.
+    pub title: Option<String>,
+    /// Short summary of the headline.
+    pub summary: Option<String>,
+    /// Image of the headline.
+    pub image: Option<Image>,
+}
+
+string_enum! {
+    /// Status of a report record.
+    pub enum ReportStatus {
+        /// The report is published.
+        Published => "published",
+        /// The report awaits review by the editors.
+        ToReview => "to-review",
+        /// The report is published but held until an embargo date.
+        Embargoed => "embargoed",
+        /// The report is archived.
+        Archive => "archive",
+    }
+}
+
+field_enum! {
+    /// Names of the fields of a report, see [`ResourceField`](crate::ResourceField).
+    pub enum ReportField {
+        Id => "id",
+        Title => "title",
+        Status => "status",
+        Body => "body",
+        BodyHtml => "body-html",
+        Origin => "origin",
+        Url => "url",
+        UrlAlias => "url_alias",
+        PrimaryCountry => "primary_country",
+        PrimaryCountryId => "primary_country.id",
+        PrimaryCountryName => "primary_country.name",
+        PrimaryCountryShortname => "primary_country.shortname",
+        PrimaryCountryIso3 => "primary_country.iso3",
+        PrimaryCountryLocation => "primary_country.location",
+        PrimaryCountryLocationLat => "primary_country.location.lat",
+        PrimaryCountryLocationLon => "primary_country.location.lon",
+        PrimaryCountryHref => "primary_country.href",
+        PrimaryCountryPrimary => "primary_country.primary",
+        Country => "country",
+        CountryId => "country.id",
+        CountryName => "country.name",
+        CountryShortname => "country.shortname",
+        CountryIso3 => "country.iso3",
+        CountryLocation => "country.location",
+        CountryLocationLat => "country.location.lat",
+        CountryLocationLon => "country.location.lon",
+        CountryPrimary => "country.primary",
+        CountryHref => "country.href",
+        Source => "source",
+        SourceId => "source.id",
+        SourceName => "source.name",
+        SourceShortname => "source.shortname",
+        SourceLongname => "source.longname",
+        SourceSpanishName => "source.spanish_name",
+        SourceHomepage => "source.homepage",
+        SourceType => "source.type",
+        SourceTypeId => "source.type.id",
+        SourceTypeName => "source.type.name",
+        SourceHref => "source.href",
+        Language => "language",
+        LanguageId => "language.id",
+        LanguageName => "language.name",
+        LanguageCode => "language.code",
+        Theme => "theme",
+        ThemeId => "theme.id",
+        ThemeName => "theme.name",
+        Format => "format",
+        FormatId => "format.id",
+        FormatName => "format.name",
+        OchaProduct => "ocha_product",
+        OchaProductId => "ocha_product.id",
+        OchaProductName => "ocha_product.name",
+        Feature => "feature",
+        FeatureId => "feature.id",
+        FeatureName => "feature.name",
+        VulnerableGroups => "vulnerable_groups",
+        VulnerableGroupsId => "vulnerable_groups.id",
+        VulnerableGroupsName => "vulnerable_groups.name",
+        Disaster => "disaster",
+        DisasterId => "disaster.id",
+        DisasterName => "disaster.name",
+        DisasterGlide => "disaster.glide",
+        DisasterStatus => "disaster.status",
+        DisasterHref => "disaster.href",
+        DisasterDisasterType => "disaster.type",
+        DisasterDisasterTypeCode => "disaster.type.code",
+        DisasterDisasterTypeId => "disaster.type.id",
+        DisasterDisasterTypeName => "disaster.type.name",
+        DisasterDisasterTypePrimary => "disaster.type.primary",
+        DisasterType => "disaster_type",
+        DisasterTypeId => "disaster_type.id",
+        DisasterTypeName => "disaster_type.name",
+        DisasterTypeCode => "disaster_type.code",
+        DisasterTypePrimary => "disaster_type.primary",
+        File => "file",
+        FileId => "file.id",
+        FileDescription => "file.description",
+        FileUrl => "file.url",
+        FileFilename => "file.filename",
+        FileMimetype => "file.mimetype",
+        FileFilesize => "file.filesize",
+        FilePreview => "file.preview",
+        FilePreviewUrl => "file.preview.url",
+        FilePreviewUrlLarge => "file.preview.url-large",
+        FilePreviewUrlSmall => "file.preview.url-small",
+        FilePreviewUrlThumb => "file.preview.url-thumb",
+        FilePreviewVersion => "file.preview.version",
+        Image => "image",
+        ImageId => "image.id",
+        ImageUrl => "image.url",
+        ImageCaption => "image.caption",
+        ImageCopyright => "image.copyright",
+        ImageFilename => "image.filename",
+        ImageMimetype => "image.mimetype",
+        ImageFilesize => "image.filesize",
+        ImageWidth => "image.width",
+        ImageHeight => "image.height",
+        ImageUrlLarge => "image.url-large",
+        ImageUrlSmall => "image.url-small",
+        ImageUrlThumb => "image.url-thumb",
+        Headline => "headline",
+        HeadlineTitle => "headline.title",
+        HeadlineSummary => "headline.summary",
+        HeadlineImage => "headline.image",
+        HeadlineImageId => "headline.image.id",
+        HeadlineImageUrl => "headline.image.url",
+        HeadlineImageCaption => "headline.image.caption",
+        HeadlineImageCopyright => "headline.image.copyright",
+        HeadlineImageFilename => "headline.image.filename",
+        HeadlineImageMimetype => "headline.image.mimetype",
+        HeadlineImageFilesize => "headline.image.filesize",
+        HeadlineImageWidth => "headline.image.width",
+        HeadlineImageHeight => "headline.image.height",
+        HeadlineImageUrlLarge => "headline.image.url-large",
+        HeadlineImageUrlSmall => "headline.image.url-small",
+        HeadlineImageUrlThumb => "headline.image.url-thumb",
+        Date => "date",
+        DateCreated => "date.created",
+        DateChanged => "date.changed",
+        DateOriginal => "date.original",
+    }
+}
+
+impl Resource for ReportFields {
+    type Field = ReportField;
 }
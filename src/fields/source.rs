@@ -7,23 +7,39 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     endpoint::ResourceEndpoint,
-    fields::common::{Country, Descriptor, DocumentDates},
+    fields::common::{Country, Descriptor, DocumentDates, Image, field_enum, string_enum},
+    resource::Resource,
 };
 
 /// Type alias for a `ResourceEndpoint` specialized for sources.
 pub type SourcesEndpoint<'c> = ResourceEndpoint<'c, SourceFields>;
 
 /// Represents the fields of a source record returned by the ReliefWeb API.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SourceFields {
     /// Unique identifier of the source.
     pub id: Option<i64>,
     /// Name of the source.
     pub name: Option<String>,
     /// Status of the source record.
-    pub status: Option<String>,
+    pub status: Option<SourceStatus>,
     /// Short name or abbreviation of the source.
     pub shortname: Option<String>,
+    /// Long name of the source.
+    pub longname: Option<String>,
+    /// Spanish name of the source (if applicable).
+    pub spanish_name: Option<String>,
+    /// Homepage URL of the source.
+    pub homepage: Option<String>,
+    /// Logo of the source.
+    pub logo: Option<Image>,
+    /// Short description of the source.
+    pub description: Option<String>,
+    /// HTML-formatted description of the source.
+    #[serde(rename = "description-html")]
+    pub description_html: Option<String>,
+    /// Disclaimer the source asks to display alongside its content.
+    pub disclaimer: Option<String>,
     /// Content types associated with the source.
     pub content_type: Option<Vec<String>>,
     /// Type of the source.
@@ -38,3 +54,67 @@ pub struct SourceFields {
     /// Various dates associated with the source (created, changed, etc.).
     pub date: Option<DocumentDates>,
 }
+
+string_enum! {
+    /// Status of a source record.
+    pub enum SourceStatus {
+        /// The source currently publishes content on ReliefWeb.
+        Active => "active",
+        /// The source no longer publishes content on ReliefWeb.
+        Inactive => "inactive",
+        /// The source is archived.
+        Archive => "archive",
+    }
+}
+
+field_enum! {
+    /// Names of the fields of a source, see [`ResourceField`](crate::ResourceField).
+    pub enum SourceField {
+        Id => "id",
+        Name => "name",
+        Shortname => "shortname",
+        Longname => "longname",
+        SpanishName => "spanish_name",
+        Status => "status",
+        Homepage => "homepage",
+        Description => "description",
+        DescriptionHtml => "description-html",
+        Disclaimer => "disclaimer",
+        ContentType => "content_type",
+        Url => "url",
+        UrlAlias => "url_alias",
+        Type => "type",
+        TypeId => "type.id",
+        TypeName => "type.name",
+        Country => "country",
+        CountryId => "country.id",
+        CountryName => "country.name",
+        CountryShortname => "country.shortname",
+        CountryIso3 => "country.iso3",
+        CountryLocation => "country.location",
+        CountryLocationLat => "country.location.lat",
+        CountryLocationLon => "country.location.lon",
+        CountryHref => "country.href",
+        CountryPrimary => "country.primary",
+        Logo => "logo",
+        LogoId => "logo.id",
+        LogoUrl => "logo.url",
+        LogoCaption => "logo.caption",
+        LogoCopyright => "logo.copyright",
+        LogoFilename => "logo.filename",
+        LogoMimetype => "logo.mimetype",
+        LogoFilesize => "logo.filesize",
+        LogoWidth => "logo.width",
+        LogoHeight => "logo.height",
+        LogoUrlLarge => "logo.url-large",
+        LogoUrlSmall => "logo.url-small",
+        LogoUrlThumb => "logo.url-thumb",
+        Date => "date",
+        DateCreated => "date.created",
+        DateChanged => "date.changed",
+    }
+}
+
+impl Resource for SourceFields {
+    type Field = SourceField;
+}
@@ -7,29 +7,41 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     endpoint::ResourceEndpoint,
-    fields::common::{Descriptor, DocumentDates, Language},
+    fields::common::{City, Country, Descriptor, DocumentDates, Language, field_enum, string_enum},
+    resource::{Archivable, Resource},
 };
 
+pub use crate::fields::common::Source;
+
 /// Type alias for a `ResourceEndpoint` specialized for trainings.
 pub type TrainingsEndpoint<'c> = ResourceEndpoint<'c, TrainingFields>;
 
 /// Represents the fields of a training record returned by the ReliefWeb API.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TrainingFields {
     /// Unique identifier of the training.
     pub id: Option<i64>,
     /// Title of the training.
     pub title: Option<String>,
     /// Status of the training record.
-    pub status: Option<String>,
-    /// Cost of attending the training.
-    pub cost: Option<String>,
+    pub status: Option<TrainingStatus>,
+    /// Whether attending the training is free or requires a fee.
+    pub cost: Option<TrainingCost>,
+    /// Details about the fee, if any.
+    pub fee_information: Option<String>,
     /// Main body content of the training record.
     pub body: Option<String>,
-    /// URL of the associated event.
+    /// URL of the event, or of the course itself for online trainings.
     pub event_url: Option<String>,
     /// Instructions on how to register for the training.
     pub how_to_register: Option<String>,
+    /// HTML-formatted instructions on how to register for the training.
+    #[serde(rename = "how_to_register-html")]
+    pub how_to_register_html: Option<String>,
+    /// Countries where the training takes place.
+    pub country: Option<Vec<Country>>,
+    /// Cities where the training takes place.
+    pub city: Option<Vec<City>>,
     /// Sources associated with the training.
     pub source: Option<Vec<Source>>,
     /// Languages available for the training.
@@ -50,28 +62,129 @@ pub struct TrainingFields {
     /// HTML body content of the training.
     #[serde(rename = "body-html")]
     pub body_html: Option<String>,
-    /// Various dates associated with the training (created, changed, etc.).
+    /// Various dates associated with the training (created, changed, etc.), including the `registration` deadline
+    /// and the `start` and `end` of the training.
     pub date: Option<DocumentDates>,
 }
 
-/// Represents a source associated with a training.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Source {
-    /// URL linking to the source.
-    pub href: Option<String>,
-    /// Unique identifier of the source.
-    pub id: Option<i64>,
-    /// Name of the source.
-    pub name: Option<String>,
-    /// Short name or abbreviation of the source.
-    pub shortname: Option<String>,
-    /// Long name of the source.
-    pub longname: Option<String>,
-    /// Spanish name of the source.
-    pub spanish_name: Option<String>,
-    /// Homepage URL of the source.
-    pub homepage: Option<String>,
-    /// Type descriptor of the source.
-    #[serde(rename = "type")]
-    pub source_type: Option<Descriptor>,
+impl TrainingFields {
+    /// Returns `true` if the training is given online, according to its formats.
+    pub fn is_online(&self) -> bool {
+        self.format.iter().flatten().any(|format| {
+            format
+                .name
+                .as_deref()
+                .is_some_and(|name| name.to_lowercase().contains("online"))
+        })
+    }
+
+    /// Returns the URL to follow an online training, if the training is given online.
+    pub fn online_url(&self) -> Option<&str> {
+        self.event_url.as_deref().filter(|_| self.is_online())
+    }
+
+    /// Returns `true` if attending the training is free.
+    pub fn is_free(&self) -> bool {
+        self.cost == Some(TrainingCost::Free)
+    }
+
+    /// Returns `true` if the training no longer accepts registrations, i.e. its registration deadline has passed.
+    #[cfg(feature = "chrono")]
+    pub fn is_registration_closed(&self) -> bool {
+        self.date
+            .as_ref()
+            .is_some_and(DocumentDates::is_registration_closed)
+    }
+}
+
+string_enum! {
+    /// Represents whether attending a training is free or requires a fee.
+    pub enum TrainingCost {
+        /// The training is free.
+        Free => "free",
+        /// The training requires a fee, described in `fee_information`.
+        Fee => "fee-based",
+    }
 }
+
+string_enum! {
+    /// Status of a training record.
+    pub enum TrainingStatus {
+        /// The training is published and accepts registrations.
+        Published => "published",
+        /// The training reached its registration deadline or end date.
+        Expired => "expired",
+        /// The training awaits review by the editors.
+        ToReview => "to-review",
+    }
+}
+
+field_enum! {
+    /// Names of the fields of a training, see [`ResourceField`](crate::ResourceField).
+    pub enum TrainingField {
+        Id => "id",
+        Title => "title",
+        Status => "status",
+        Body => "body",
+        BodyHtml => "body-html",
+        Cost => "cost",
+        FeeInformation => "fee_information",
+        EventUrl => "event_url",
+        HowToRegister => "how_to_register",
+        HowToRegisterHtml => "how_to_register-html",
+        Url => "url",
+        UrlAlias => "url_alias",
+        Source => "source",
+        SourceId => "source.id",
+        SourceName => "source.name",
+        SourceShortname => "source.shortname",
+        SourceLongname => "source.longname",
+        SourceSpanishName => "source.spanish_name",
+        SourceHomepage => "source.homepage",
+        SourceType => "source.type",
+        SourceTypeId => "source.type.id",
+        SourceTypeName => "source.type.name",
+        SourceHref => "source.href",
+        Country => "country",
+        CountryId => "country.id",
+        CountryName => "country.name",
+        CountryShortname => "country.shortname",
+        CountryIso3 => "country.iso3",
+        CountryLocation => "country.location",
+        CountryLocationLat => "country.location.lat",
+        CountryLocationLon => "country.location.lon",
+        CountryHref => "country.href",
+        CountryPrimary => "country.primary",
+        City => "city",
+        CityName => "city.name",
+        Language => "language",
+        LanguageId => "language.id",
+        LanguageName => "language.name",
+        LanguageCode => "language.code",
+        TrainingLanguage => "training_language",
+        TrainingLanguageId => "training_language.id",
+        TrainingLanguageName => "training_language.name",
+        TrainingLanguageCode => "training_language.code",
+        Theme => "theme",
+        ThemeId => "theme.id",
+        ThemeName => "theme.name",
+        Type => "type",
+        TypeId => "type.id",
+        TypeName => "type.name",
+        Format => "format",
+        FormatId => "format.id",
+        FormatName => "format.name",
+        Date => "date",
+        DateCreated => "date.created",
+        DateChanged => "date.changed",
+        DateRegistration => "date.registration",
+        DateStart => "date.start",
+        DateEnd => "date.end",
+    }
+}
+
+impl Resource for TrainingFields {
+    type Field = TrainingField;
+}
+
+impl Archivable for TrainingFields {}
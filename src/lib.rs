@@ -23,12 +23,24 @@
 
 mod client;
 mod endpoint;
+mod error;
 mod fields;
+mod pagination;
 mod params;
+mod rate_limit;
+mod resource;
 mod response;
+mod retry;
+mod search;
 
 pub use client::*;
 pub use endpoint::*;
+pub use error::*;
 pub use fields::*;
+pub use pagination::*;
 pub use params::*;
+pub use rate_limit::*;
+pub use resource::*;
 pub use response::*;
+pub use retry::*;
+pub use search::*;
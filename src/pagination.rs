@@ -0,0 +1,315 @@
+use futures::{Stream, TryStreamExt, stream};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    endpoint::ResourceEndpoint,
+    error::{Error, Result},
+    params::QueryParams,
+    response::{ApiItem, ApiResponse},
+};
+
+/// Default number of items requested per page when paginating.
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of items the API returns for a single request.
+pub const MAX_PAGE_SIZE: u32 = 1000;
+
+/// Options controlling how [`ResourceEndpoint::pages`] and [`ResourceEndpoint::list_stream`] walk through results.
+///
+/// # Example
+///
+/// ```
+/// use reliefweb::PaginationOptions;
+///
+/// let options = PaginationOptions::new().page_size(500).max_items(2000);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaginationOptions {
+    /// Number of items requested per page. Must be between 1 and 1000, defaults to 100.
+    pub page_size: u32,
+    /// Hard cap on the total number of items yielded, across all pages.
+    pub max_items: Option<u32>,
+}
+
+impl Default for PaginationOptions {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            max_items: None,
+        }
+    }
+}
+
+impl PaginationOptions {
+    /// Create a default set of pagination options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page_size = size;
+        self
+    }
+
+    pub fn max_items(mut self, max: u32) -> Self {
+        self.max_items = Some(max);
+        self
+    }
+}
+
+/// Internal cursor tracking the progress of a paginated listing.
+struct PageCursor {
+    params: QueryParams,
+    offset: u32,
+    yielded: u32,
+    done: bool,
+}
+
+impl<'c, T> ResourceEndpoint<'c, T>
+where
+    T: DeserializeOwned,
+{
+    /// Stream every page of a `list` request, issuing one request per page.
+    ///
+    /// Paging is driven by offsets: it starts at `params.offset` and advances the offset by the number of items received,
+    /// until a page is shorter than requested, the API stops advertising a `next` link, `total_count` is reached, or
+    /// `options.max_items` items were yielded. The URL of the `next` link is not followed, its absence only signals the
+    /// last page; a response without `links` is paged through like one with a `next` link.
+    /// The `limit` of `params` is ignored in favour of `options.page_size`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use reliefweb::{Client, APIVersion, PaginationOptions, QueryParams};
+    ///
+    /// # async fn run() -> reliefweb::Result<()> {
+    /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2)?;
+    /// let reports = client.reports();
+    /// let mut pages = Box::pin(reports.pages(QueryParams::new(), PaginationOptions::new().page_size(500)));
+    /// while let Some(page) = pages.try_next().await? {
+    ///     println!("Got {} reports", page.data.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pages(
+        &self,
+        params: QueryParams,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<ApiResponse<T>>> {
+        let cursor = PageCursor {
+            offset: params.offset.unwrap_or(0),
+            params,
+            yielded: 0,
+            done: false,
+        };
+        stream::try_unfold(cursor, move |mut cursor| {
+            let options = options.clone();
+            async move {
+                if options.page_size == 0 || options.page_size > MAX_PAGE_SIZE {
+                    return Err(Error::InvalidParameters(format!(
+                        "page size must be between 1 and {MAX_PAGE_SIZE}, got {}",
+                        options.page_size
+                    )));
+                }
+                let remaining = options
+                    .max_items
+                    .map(|max| max.saturating_sub(cursor.yielded));
+                if cursor.done || remaining == Some(0) {
+                    return Ok(None);
+                }
+                let limit = remaining.map_or(options.page_size, |r| r.min(options.page_size));
+
+                cursor.params.limit = Some(limit);
+                cursor.params.offset = Some(cursor.offset);
+                let page = self.list(Some(&cursor.params)).await?;
+
+                let received = page.data.len() as u32;
+                cursor.offset += received;
+                cursor.yielded += received;
+                // Only the presence of the `next` link matters, as the next page is requested by offset.
+                let has_next = page.links.as_ref().is_none_or(|l| l.next.is_some());
+                let reached_total = page.total_count.is_some_and(|t| cursor.offset >= t);
+                cursor.done = received < limit || !has_next || reached_total;
+
+                if received == 0 {
+                    return Ok(None);
+                }
+                Ok(Some((page, cursor)))
+            }
+        })
+    }
+
+    /// Stream every item of a `list` request, transparently fetching pages as needed.
+    ///
+    /// See [`ResourceEndpoint::pages`] for how paging proceeds and terminates.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use reliefweb::{Client, APIVersion, PaginationOptions, QueryParams};
+    ///
+    /// # async fn run() -> reliefweb::Result<()> {
+    /// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2)?;
+    /// let reports = client.reports();
+    /// let titles: Vec<_> = reports
+    ///     .list_stream(QueryParams::new(), PaginationOptions::new().max_items(250))
+    ///     .map_ok(|item| item.fields.title)
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_stream(
+        &self,
+        params: QueryParams,
+        options: PaginationOptions,
+    ) -> impl Stream<Item = Result<ApiItem<T>>> {
+        self.pages(params, options)
+            .map_ok(|page| stream::iter(page.data.into_iter().map(Ok)))
+            .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+    use httpmock::prelude::*;
+
+    use super::*;
+    use crate::Client;
+
+    fn client(server: &MockServer) -> Client {
+        Client::new_with_scheme(
+            "http",
+            format!("{}:{}", server.host(), server.port()).as_str(),
+            "testapp",
+            crate::APIVersion::V2,
+        )
+        .unwrap()
+    }
+
+    fn page(ids: std::ops::Range<u32>, total: u32, next: bool) -> serde_json::Value {
+        let data: Vec<_> = ids
+            .map(|i| serde_json::json!({ "id": i.to_string(), "fields": { "title": format!("Report {i}") } }))
+            .collect();
+        let mut links = serde_json::json!({ "self": { "href": "http://example.com" } });
+        if next {
+            links["next"] = serde_json::json!({ "href": "http://example.com/next" });
+        }
+        serde_json::json!({ "totalCount": total, "count": data.len(), "links": links, "data": data })
+    }
+
+    #[tokio::test]
+    async fn list_stream_follows_offsets_until_total() {
+        let server = MockServer::start();
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v2/reports")
+                .query_param("limit", "2")
+                .query_param("offset", "0");
+            then.status(200).json_body_obj(&page(0..2, 5, true));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v2/reports")
+                .query_param("limit", "2")
+                .query_param("offset", "2");
+            then.status(200).json_body_obj(&page(2..4, 5, true));
+        });
+        let third = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v2/reports")
+                .query_param("limit", "2")
+                .query_param("offset", "4");
+            then.status(200).json_body_obj(&page(4..5, 5, false));
+        });
+
+        let client = client(&server);
+        let items: Vec<_> = client
+            .reports()
+            .list_stream(QueryParams::new(), PaginationOptions::new().page_size(2))
+            .try_collect()
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["0", "1", "2", "3", "4"]);
+        first.assert();
+        second.assert();
+        third.assert();
+    }
+
+    #[tokio::test]
+    async fn pages_respects_max_items() {
+        let server = MockServer::start();
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v2/reports")
+                .query_param("limit", "2")
+                .query_param("offset", "10");
+            then.status(200).json_body_obj(&page(10..12, 100, true));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v2/reports")
+                .query_param("limit", "1")
+                .query_param("offset", "12");
+            then.status(200).json_body_obj(&page(12..13, 100, true));
+        });
+
+        let client = client(&server);
+        let pages: Vec<_> = client
+            .reports()
+            .pages(
+                QueryParams::new().offset(10),
+                PaginationOptions::new().page_size(2).max_items(3),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[1].data[0].id, "12");
+        first.assert();
+        second.assert();
+    }
+
+    #[tokio::test]
+    async fn pages_stop_without_next_link() {
+        let server = MockServer::start();
+        let last = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v2/reports")
+                .query_param("limit", "2")
+                .query_param("offset", "0");
+            then.status(200).json_body_obj(&page(0..2, 100, false));
+        });
+
+        let client = client(&server);
+        let items: Vec<_> = client
+            .reports()
+            .list_stream(QueryParams::new(), PaginationOptions::new().page_size(2))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 2);
+        last.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn pages_rejects_invalid_page_size() {
+        let client =
+            Client::new_with_scheme("http", "localhost:1", "testapp", crate::APIVersion::V2)
+                .unwrap();
+        let result: Result<Vec<_>> = client
+            .reports()
+            .pages(QueryParams::new(), PaginationOptions::new().page_size(0))
+            .try_collect()
+            .await;
+        assert!(matches!(result, Err(Error::InvalidParameters(_))));
+    }
+}
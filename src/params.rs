@@ -1,9 +1,18 @@
 use std::fmt;
 
 use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+
+use crate::{
+    error::{Error, Result, ValidationError},
+    pagination::MAX_PAGE_SIZE,
+    resource::{ResourceField, is_known_field},
+};
 
 /// `QueryProfile` specifies which sets of fields to include in result.
-#[derive(Default)]
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum QueryProfile {
     /// Just the `title` or `name` field
     #[default]
@@ -25,7 +34,8 @@ impl fmt::Display for QueryProfile {
 }
 
 /// A shorthand specification of sets of fields, filters and sort order for common use-cases. Similar to `profile` but with more opinions
-#[derive(Default)]
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum QueryPreset {
     ///The default setting applies sensible status filters for most requests
     #[default]
@@ -46,7 +56,7 @@ impl fmt::Display for QueryPreset {
     }
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 /// Specifies how to interpret spaces in queries. Can be AND or OR. Default value is OR.
 pub enum FilterOperator {
     #[default]
@@ -64,30 +74,547 @@ impl fmt::Display for FilterOperator {
 }
 
 /// Specifies a full-text filter for the query
-#[derive(Default, Debug, PartialEq)]
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct QueryQuery {
     /// What to search for. Required for all queries.
     pub value: String,
     /// Which fields to query on. See [field tables](https://apidoc.reliefweb.int/fields-tables) for options.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<String>,
     /// How to interpret spaces in the query. Can be AND or OR. Default value is OR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub operator: Option<FilterOperator>,
 }
 
+impl QueryQuery {
+    /// Create a full-text query searching for `value` in all fields.
+    pub fn new(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Restrict the search to the given fields, e.g. `[ReportField::Title, ReportField::Body]`.
+    pub fn fields(mut self, fields: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.fields.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set how to interpret spaces in the query. Can be AND or OR.
+    pub fn operator(mut self, operator: FilterOperator) -> Self {
+        self.operator = Some(operator);
+        self
+    }
+}
+
 /// `Narrows down the content to be searched in. These correspond to the 'refine' section of the search bar.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct QueryFilter {
     ///Which field to filter on. See [field tables](https://apidoc.reliefweb.int/fields-tables).
     pub field: String,
     /// The value to filter for. Most of the possible values are pre-defined. If this is for a `date`, or numeric value (e.g. `id`), it can be a range defined by `from` and `to` values. If only `from` or `to` is present, then value will match those greater than or equal to or less than or equal to the value respectively. If `value` is missing, the filter will act on whether the field exists or not.
-    pub value: String,
-    /// How to combine filter array values or conditions. Can be AND or OR.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub value: FilterValue,
+    /// How to combine the values of this condition when it has several. Can be AND or OR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub operator: Option<FilterOperator>,
     /// Set to `true` to select all items that do not match the filter.
+    #[serde(default, skip_serializing_if = "is_default")]
     pub negate: bool,
 }
 
+/// The value a [`QueryFilter`] matches against.
+///
+/// # Example
+///
+/// ```
+/// use reliefweb::{Filter, FilterValue};
+///
+/// let exact = Filter::condition("country.iso3", "syr");
+/// let ids = Filter::condition("id", FilterValue::between(1000, 2000));
+/// let recent = Filter::condition("date.created", FilterValue::at_least("2024-01-01T00:00:00+00:00"));
+/// let with_image = Filter::exists("image");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(untagged, from = "FilterValueRepr")]
+pub enum FilterValue {
+    /// Matches items whose field has exactly this value.
+    Value(String),
+    /// Matches items whose field has the given values, combined with the condition's own `operator`:
+    /// any of them with OR, all of them with AND.
+    Values(Vec<String>),
+    /// Matches items whose date or numeric field is within the given inclusive bounds. A missing bound leaves the range open.
+    Range {
+        /// Lower bound of the range.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        from: Option<String>,
+        /// Upper bound of the range.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        to: Option<String>,
+    },
+    /// Matches items for which the field exists.
+    #[default]
+    Exists,
+}
+
+/// The deserialized form of [`FilterValue`], which rejects unknown keys in ranges.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FilterValueRepr {
+    Value(String),
+    Values(Vec<String>),
+    Range(RangeBounds),
+    Exists,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RangeBounds {
+    #[serde(default)]
+    from: Option<String>,
+    #[serde(default)]
+    to: Option<String>,
+}
+
+impl From<FilterValueRepr> for FilterValue {
+    fn from(repr: FilterValueRepr) -> Self {
+        match repr {
+            FilterValueRepr::Value(value) => FilterValue::Value(value),
+            FilterValueRepr::Values(values) => FilterValue::Values(values),
+            FilterValueRepr::Range(RangeBounds { from, to }) => FilterValue::Range { from, to },
+            FilterValueRepr::Exists => FilterValue::Exists,
+        }
+    }
+}
+
+impl FilterValue {
+    /// Create a range matching values between `from` and `to`, inclusive.
+    pub fn between(from: impl RangeBound, to: impl RangeBound) -> Self {
+        FilterValue::Range {
+            from: Some(from.to_bound()),
+            to: Some(to.to_upper_bound()),
+        }
+    }
+
+    /// Create an open range matching values greater than or equal to `from`.
+    pub fn at_least(from: impl RangeBound) -> Self {
+        FilterValue::Range {
+            from: Some(from.to_bound()),
+            to: None,
+        }
+    }
+
+    /// Create an open range matching values less than or equal to `to`.
+    pub fn at_most(to: impl RangeBound) -> Self {
+        FilterValue::Range {
+            from: None,
+            to: Some(to.to_upper_bound()),
+        }
+    }
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        FilterValue::Value(value.to_string())
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        FilterValue::Value(value)
+    }
+}
+
+impl<T: Into<String>> From<Vec<T>> for FilterValue {
+    fn from(values: Vec<T>) -> Self {
+        FilterValue::Values(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<String>, const N: usize> From<[T; N]> for FilterValue {
+    fn from(values: [T; N]) -> Self {
+        FilterValue::Values(values.into_iter().map(Into::into).collect())
+    }
+}
+
+/// A value usable as a bound of a [`FilterValue::Range`], such as an id or a date.
+///
+/// With the `chrono` or `time` features, dates and datetimes of these crates are formatted in the ISO 8601 form expected by the API.
+/// A date without time covers the whole day: it starts at midnight as a lower bound, and ends a second before the next midnight
+/// as an upper bound.
+pub trait RangeBound {
+    /// Returns the bound as sent to the API.
+    fn to_bound(&self) -> String;
+
+    /// Returns the bound as sent to the API when it is the upper bound of a range. Defaults to [`RangeBound::to_bound`].
+    fn to_upper_bound(&self) -> String {
+        self.to_bound()
+    }
+}
+
+impl RangeBound for &str {
+    fn to_bound(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl RangeBound for String {
+    fn to_bound(&self) -> String {
+        self.clone()
+    }
+}
+
+macro_rules! impl_numeric_range_bound {
+    ($($t:ty),*) => {
+        $(
+            impl RangeBound for $t {
+                fn to_bound(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_range_bound!(i32, i64, u32, u64, usize);
+
+#[cfg(feature = "chrono")]
+impl<Tz> RangeBound for chrono::DateTime<Tz>
+where
+    Tz: chrono::TimeZone,
+    Tz::Offset: fmt::Display,
+{
+    fn to_bound(&self) -> String {
+        self.format("%Y-%m-%dT%H:%M:%S%:z").to_string()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl RangeBound for chrono::NaiveDate {
+    fn to_bound(&self) -> String {
+        self.format("%Y-%m-%dT00:00:00+00:00").to_string()
+    }
+
+    fn to_upper_bound(&self) -> String {
+        self.format("%Y-%m-%dT23:59:59+00:00").to_string()
+    }
+}
+
+#[cfg(feature = "time")]
+impl RangeBound for time::OffsetDateTime {
+    fn to_bound(&self) -> String {
+        let format = time::macros::format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"
+        );
+        self.format(&format)
+            .expect("a datetime with a 4-digit year can be formatted")
+    }
+}
+
+#[cfg(feature = "time")]
+impl RangeBound for time::Date {
+    fn to_bound(&self) -> String {
+        self.midnight().assume_utc().to_bound()
+    }
+
+    fn to_upper_bound(&self) -> String {
+        self.with_hms(23, 59, 59)
+            .expect("23:59:59 is a valid time")
+            .assume_utc()
+            .to_bound()
+    }
+}
+
+impl QueryFilter {
+    /// Create a condition matching items whose `field` has the given `value`.
+    pub fn new(field: impl Into<String>, value: impl Into<FilterValue>) -> Self {
+        Self {
+            field: field.into(),
+            value: value.into(),
+            operator: None,
+            negate: false,
+        }
+    }
+
+    /// Create a condition matching items whose `field` has any of the given `values`.
+    pub fn any_of(field: impl Into<String>, values: impl Into<FilterValue>) -> Self {
+        Self::new(field, values).operator(FilterOperator::OR)
+    }
+
+    /// Create a condition matching items whose `field` has all of the given `values`.
+    pub fn all_of(field: impl Into<String>, values: impl Into<FilterValue>) -> Self {
+        Self::new(field, values).operator(FilterOperator::AND)
+    }
+
+    /// Set how the values of this condition are combined. Can be AND or OR.
+    pub fn operator(mut self, operator: FilterOperator) -> Self {
+        self.operator = Some(operator);
+        self
+    }
+}
+
+/// A group of filters combined with a boolean operator, possibly negated.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FilterGroup {
+    /// The nested filters of the group.
+    pub conditions: Vec<Filter>,
+    /// How to combine the nested filters. Can be AND or OR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operator: Option<FilterOperator>,
+    /// Set to `true` to select all items that do not match the group.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub negate: bool,
+}
+
+impl FilterGroup {
+    /// Returns `true` if the group contains no filter.
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+
+    /// Returns the number of filters directly nested in the group.
+    pub fn len(&self) -> usize {
+        self.conditions.len()
+    }
+}
+
+/// A node of a filter tree: either a single condition on a field, or a group of nested filters.
+///
+/// # Example
+///
+/// ```
+/// use reliefweb::{Filter, QueryParams};
+///
+/// // (country=Syria OR country=Iraq) AND NOT format=Map
+/// let filter = Filter::and([
+///     Filter::or([
+///         Filter::condition("country", "Syria"),
+///         Filter::condition("country", "Iraq"),
+///     ]),
+///     !Filter::condition("format", "Map"),
+/// ]);
+/// let params = QueryParams::new().filter(filter);
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Filter {
+    /// A condition on a single field.
+    Condition(QueryFilter),
+    /// A group of nested filters.
+    Group(FilterGroup),
+}
+
+impl Filter {
+    /// Create a condition matching items whose `field` has the given `value`.
+    pub fn condition(field: impl Into<String>, value: impl Into<FilterValue>) -> Self {
+        Filter::Condition(QueryFilter::new(field, value))
+    }
+
+    /// Create a condition matching items whose `field` has any of the given `values`.
+    ///
+    /// ```
+    /// use reliefweb::Filter;
+    ///
+    /// let filter = Filter::any_of("country.iso3", ["syr", "irq", "leb"]);
+    /// ```
+    pub fn any_of(field: impl Into<String>, values: impl Into<FilterValue>) -> Self {
+        Filter::Condition(QueryFilter::any_of(field, values))
+    }
+
+    /// Create a condition matching items whose `field` has all of the given `values`.
+    pub fn all_of(field: impl Into<String>, values: impl Into<FilterValue>) -> Self {
+        Filter::Condition(QueryFilter::all_of(field, values))
+    }
+
+    /// Create a condition matching items for which `field` exists.
+    pub fn exists(field: impl Into<String>) -> Self {
+        Filter::Condition(QueryFilter::new(field, FilterValue::Exists))
+    }
+
+    /// Create a group matching items that match all the given filters.
+    pub fn and(filters: impl IntoIterator<Item = impl Into<Filter>>) -> Self {
+        Self::group(FilterOperator::AND, filters)
+    }
+
+    /// Create a group matching items that match any of the given filters.
+    pub fn or(filters: impl IntoIterator<Item = impl Into<Filter>>) -> Self {
+        Self::group(FilterOperator::OR, filters)
+    }
+
+    fn group(
+        operator: FilterOperator,
+        filters: impl IntoIterator<Item = impl Into<Filter>>,
+    ) -> Self {
+        Filter::Group(FilterGroup {
+            conditions: filters.into_iter().map(Into::into).collect(),
+            operator: Some(operator),
+            negate: false,
+        })
+    }
+
+    /// Invert the filter, to select all items that do not match it.
+    pub fn negate(mut self) -> Self {
+        match &mut self {
+            Filter::Condition(c) => c.negate = !c.negate,
+            Filter::Group(g) => g.negate = !g.negate,
+        }
+        self
+    }
+
+    /// Collect every condition of the filter, including those of nested groups.
+    fn collect_conditions<'a>(&'a self, out: &mut Vec<&'a QueryFilter>) {
+        match self {
+            Filter::Condition(c) => out.push(c),
+            Filter::Group(g) => g.collect_conditions(out),
+        }
+    }
+
+    /// Returns the number of groups without conditions in the filter, including itself.
+    fn empty_groups(&self) -> usize {
+        match self {
+            Filter::Condition(_) => 0,
+            Filter::Group(g) => {
+                usize::from(g.conditions.is_empty())
+                    + g.conditions.iter().map(Filter::empty_groups).sum::<usize>()
+            }
+        }
+    }
+
+    /// Append the URL-encoded form of the filter under the given key prefix, e.g. `filter[conditions][0]`.
+    fn append_pairs(&self, prefix: &str, pairs: &mut Vec<(String, String)>) {
+        match self {
+            Filter::Condition(c) => {
+                pairs.push((format!("{prefix}[field]"), c.field.clone()));
+                match &c.value {
+                    FilterValue::Value(v) => pairs.push((format!("{prefix}[value]"), v.clone())),
+                    FilterValue::Values(values) => {
+                        for v in values {
+                            pairs.push((format!("{prefix}[value][]"), v.clone()));
+                        }
+                    }
+                    FilterValue::Range { from, to } => {
+                        if let Some(from) = from {
+                            pairs.push((format!("{prefix}[value][from]"), from.clone()));
+                        }
+                        if let Some(to) = to {
+                            pairs.push((format!("{prefix}[value][to]"), to.clone()));
+                        }
+                    }
+                    FilterValue::Exists => {}
+                }
+                if c.negate {
+                    pairs.push((format!("{prefix}[negate]"), "1".to_string()));
+                }
+                if let Some(op) = &c.operator {
+                    pairs.push((format!("{prefix}[operator]"), op.to_string()));
+                }
+            }
+            Filter::Group(g) => g.append_pairs(prefix, pairs),
+        }
+    }
+
+    /// Serialize the filter to the JSON form of the API.
+    fn to_json(&self) -> Value {
+        match self {
+            Filter::Condition(c) => {
+                let mut obj = Map::new();
+                obj.insert("field".into(), json!(c.field));
+                match &c.value {
+                    FilterValue::Value(v) => {
+                        obj.insert("value".into(), json!(v));
+                    }
+                    FilterValue::Values(values) => {
+                        obj.insert("value".into(), json!(values));
+                    }
+                    FilterValue::Range { from, to } => {
+                        let mut range = Map::new();
+                        if let Some(from) = from {
+                            range.insert("from".into(), json!(from));
+                        }
+                        if let Some(to) = to {
+                            range.insert("to".into(), json!(to));
+                        }
+                        obj.insert("value".into(), Value::Object(range));
+                    }
+                    FilterValue::Exists => {}
+                }
+                if c.negate {
+                    obj.insert("negate".into(), json!(true));
+                }
+                if let Some(op) = &c.operator {
+                    obj.insert("operator".into(), json!(op.to_string()));
+                }
+                Value::Object(obj)
+            }
+            Filter::Group(g) => g.to_json(),
+        }
+    }
+}
+
+impl FilterGroup {
+    fn collect_conditions<'a>(&'a self, out: &mut Vec<&'a QueryFilter>) {
+        for condition in &self.conditions {
+            condition.collect_conditions(out);
+        }
+    }
+
+    /// Append the URL-encoded form of the group, unless it has no conditions, as such a group has nothing to encode.
+    fn append_pairs(&self, prefix: &str, pairs: &mut Vec<(String, String)>) {
+        if self.conditions.is_empty() {
+            return;
+        }
+        if let Some(op) = &self.operator {
+            pairs.push((format!("{prefix}[operator]"), op.to_string()));
+        }
+        for (i, f) in self.conditions.iter().enumerate() {
+            f.append_pairs(&format!("{prefix}[conditions][{i}]"), pairs);
+        }
+        if self.negate {
+            pairs.push((format!("{prefix}[negate]"), "1".to_string()));
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let mut obj = Map::new();
+        if let Some(op) = &self.operator {
+            obj.insert("operator".into(), json!(op.to_string()));
+        }
+        let conditions = self.conditions.iter().map(Filter::to_json).collect();
+        obj.insert("conditions".into(), Value::Array(conditions));
+        if self.negate {
+            obj.insert("negate".into(), json!(true));
+        }
+        Value::Object(obj)
+    }
+}
+
+impl From<QueryFilter> for Filter {
+    fn from(filter: QueryFilter) -> Self {
+        Filter::Condition(filter)
+    }
+}
+
+impl From<FilterGroup> for Filter {
+    fn from(group: FilterGroup) -> Self {
+        Filter::Group(group)
+    }
+}
+
+impl std::ops::Not for Filter {
+    type Output = Filter;
+
+    fn not(self) -> Self::Output {
+        self.negate()
+    }
+}
+
 /// Specifies the sorting direction of results for a given field.
-#[derive(Default)]
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SortDirection {
     #[default]
     Asc,
@@ -104,11 +631,241 @@ impl fmt::Display for SortDirection {
 }
 
 /// Specifies how results should be sorted for a given field.
+///
+/// It is serialized like in the API, as `field:direction`, e.g. `"date.created:desc"`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
 pub struct SortDescriptor {
     pub field: String,
     pub direction: SortDirection,
 }
 
+impl SortDescriptor {
+    /// Sort results by `field` in the given direction.
+    pub fn new(field: impl Into<String>, direction: SortDirection) -> Self {
+        Self {
+            field: field.into(),
+            direction,
+        }
+    }
+
+    /// Sort results by `field` in ascending order.
+    pub fn asc(field: impl Into<String>) -> Self {
+        Self::new(field, SortDirection::Asc)
+    }
+
+    /// Sort results by `field` in descending order.
+    pub fn desc(field: impl Into<String>) -> Self {
+        Self::new(field, SortDirection::Desc)
+    }
+}
+
+impl From<SortDescriptor> for String {
+    fn from(sort: SortDescriptor) -> Self {
+        format!("{}:{}", sort.field, sort.direction)
+    }
+}
+
+impl TryFrom<String> for SortDescriptor {
+    type Error = Error;
+
+    fn try_from(sort: String) -> Result<Self> {
+        let (field, direction) = sort
+            .rsplit_once(':')
+            .ok_or_else(|| invalid_value("sort", &sort))?;
+        Ok(SortDescriptor::new(field, parse_direction(direction)?))
+    }
+}
+
+/// Specifies what facet buckets are sorted by.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FacetSortBy {
+    /// Sort buckets by number of matching items.
+    Count,
+    /// Sort buckets by their value.
+    Value,
+}
+
+impl fmt::Display for FacetSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FacetSortBy::Count => write!(f, "count"),
+            FacetSortBy::Value => write!(f, "value"),
+        }
+    }
+}
+
+/// Specifies the interval of the buckets of a date facet.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FacetInterval {
+    Year,
+    Month,
+    Week,
+    Day,
+}
+
+impl fmt::Display for FacetInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FacetInterval::Year => write!(f, "year"),
+            FacetInterval::Month => write!(f, "month"),
+            FacetInterval::Week => write!(f, "week"),
+            FacetInterval::Day => write!(f, "day"),
+        }
+    }
+}
+
+/// Specifies which items a facet is computed on.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FacetScope {
+    /// Compute the facet on the items matching the query and filters of the request.
+    Query,
+    /// Compute the facet on all items, ignoring the query and filters of the request.
+    Global,
+}
+
+impl fmt::Display for FacetScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FacetScope::Query => write!(f, "query"),
+            FacetScope::Global => write!(f, "global"),
+        }
+    }
+}
+
+/// Requests the API to aggregate matching items by the values of a field, returned in the `embedded.facets` section of the response.
+///
+/// # Example
+///
+/// ```
+/// use reliefweb::{FacetInterval, FacetSortBy, QueryFacet, QueryParams, SortDirection};
+///
+/// let params = QueryParams::new()
+///     .limit(0)
+///     .facet(QueryFacet::new("country").limit(20).sort(FacetSortBy::Count, SortDirection::Desc))
+///     .facet(QueryFacet::new("date.created").name("per_month").interval(FacetInterval::Month));
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QueryFacet {
+    /// Which field to aggregate on. See [field tables](https://apidoc.reliefweb.int/fields-tables).
+    pub field: String,
+    /// Name of the facet in the response. Defaults to the field name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Maximum number of buckets to return.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// How to sort the buckets.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "facet_sort")]
+    pub sort: Option<(FacetSortBy, SortDirection)>,
+    /// Bucket interval, for date fields.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<FacetInterval>,
+    /// Restricts the items the facet is computed on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Filter>,
+    /// Whether the facet is computed on the items matching the request, or on all items.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<FacetScope>,
+}
+
+impl QueryFacet {
+    /// Create a facet aggregating on the given field.
+    pub fn new(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            name: None,
+            limit: None,
+            sort: None,
+            interval: None,
+            filter: None,
+            scope: None,
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn sort(mut self, by: FacetSortBy, direction: SortDirection) -> Self {
+        self.sort = Some((by, direction));
+        self
+    }
+
+    pub fn interval(mut self, interval: FacetInterval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    pub fn filter(mut self, filter: impl Into<Filter>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    pub fn scope(mut self, scope: FacetScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Append the URL-encoded form of the facet under the given key prefix, e.g. `facets[0]`.
+    fn append_pairs(&self, prefix: &str, pairs: &mut Vec<(String, String)>) {
+        pairs.push((format!("{prefix}[field]"), self.field.clone()));
+        if let Some(name) = &self.name {
+            pairs.push((format!("{prefix}[name]"), name.clone()));
+        }
+        if let Some(limit) = self.limit {
+            pairs.push((format!("{prefix}[limit]"), limit.to_string()));
+        }
+        if let Some((by, direction)) = &self.sort {
+            pairs.push((format!("{prefix}[sort]"), format!("{by}:{direction}")));
+        }
+        if let Some(interval) = &self.interval {
+            pairs.push((format!("{prefix}[interval]"), interval.to_string()));
+        }
+        if let Some(filter) = &self.filter {
+            filter.append_pairs(&format!("{prefix}[filter]"), pairs);
+        }
+        if let Some(scope) = &self.scope {
+            pairs.push((format!("{prefix}[scope]"), scope.to_string()));
+        }
+    }
+
+    /// Serialize the facet to the JSON form of the API.
+    fn to_json(&self) -> Value {
+        let mut obj = Map::new();
+        obj.insert("field".into(), json!(self.field));
+        if let Some(name) = &self.name {
+            obj.insert("name".into(), json!(name));
+        }
+        if let Some(limit) = self.limit {
+            obj.insert("limit".into(), json!(limit));
+        }
+        if let Some((by, direction)) = &self.sort {
+            obj.insert("sort".into(), json!(format!("{by}:{direction}")));
+        }
+        if let Some(interval) = &self.interval {
+            obj.insert("interval".into(), json!(interval.to_string()));
+        }
+        if let Some(filter) = &self.filter {
+            obj.insert("filter".into(), filter.to_json());
+        }
+        if let Some(scope) = &self.scope {
+            obj.insert("scope".into(), json!(scope.to_string()));
+        }
+        Value::Object(obj)
+    }
+}
+
 // Query parameters for filtering, sorting, and field selection.
 ///
 /// Provides a builder-style API to chain filters, queries, sorting, and inclusion/exclusion of fields.
@@ -116,19 +873,62 @@ pub struct SortDescriptor {
 /// # Example
 ///
 /// ```no_run
-/// use reliefweb::{QueryParams, QueryProfile};
+/// use reliefweb::{QueryParams, QueryProfile, SortDescriptor, report::ReportField};
 ///
 /// let params = QueryParams::new()
 ///     .limit(10)
 ///     .profile(QueryProfile::Minimal)
-///     .include(vec!["title".to_string(), "source".to_string()]);
+///     .include_fields([ReportField::Title, ReportField::SourceShortname])
+///     .sort(vec![SortDescriptor::desc(ReportField::DateCreated)]);
 /// ```
-#[derive(Default)]
+///
+/// Field names can also be given as strings, e.g. `.include(vec!["title".into(), "source.shortname".into()])`.
+///
+/// # Serialization
+///
+/// Query parameters can be serialized with serde, e.g. to store saved searches as JSON or TOML. The schema is the one
+/// of the API's JSON body for `POST` requests (see [`QueryParams::to_json`]), so it does not change with the layout of
+/// this struct, except that `verbose`, which the API only reads from the URL, is kept.
+///
+/// Unset or empty parameters are left out. A filter is a condition when it has a `field`, or a group when it has
+/// `conditions`; the `value` of a condition is a string, an array of strings, a range with `from` and/or `to`, or
+/// left out to match items for which the field exists. Unknown keys are rejected, so that a misspelled `limit` or
+/// `value` is not silently ignored.
+///
+/// ```
+/// use reliefweb::QueryParams;
+///
+/// let params: QueryParams = serde_json::from_str(r#"{
+///     "query": { "value": "cholera", "fields": ["title"], "operator": "AND" },
+///     "filter": {
+///         "operator": "AND",
+///         "conditions": [
+///             { "field": "country.iso3", "value": ["hti", "dom"], "operator": "OR" },
+///             { "field": "date.created", "value": { "from": "2024-01-01T00:00:00+00:00" } },
+///             { "field": "format.name", "value": "Map", "negate": true },
+///             { "conditions": [{ "field": "image" }, { "field": "file" }], "operator": "OR" }
+///         ]
+///     },
+///     "sort": ["date.created:desc"],
+///     "fields": { "include": ["title", "url"] },
+///     "facets": [{ "field": "source.shortname", "limit": 5, "sort": "count:desc" }],
+///     "profile": "list",
+///     "preset": "latest",
+///     "limit": 20,
+///     "offset": 40
+/// }"#).unwrap();
+///
+/// assert_eq!(serde_json::from_str::<QueryParams>(&serde_json::to_string(&params).unwrap()).unwrap(), params);
+/// ```
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "QueryBody", into = "QueryBody")]
 pub struct QueryParams {
     /// Free-text search in given fields.
     pub query: Option<QueryQuery>,
     /// Narrows down content to be searched in. Corresponds to the 'refine' section in the web UI.
-    pub filter: Vec<QueryFilter>,
+    ///
+    /// This is the root group of the filter tree: filters added with [`QueryParams::filter`] are combined with its `operator`.
+    pub filter: FilterGroup,
     ///A helper for creating correct API calls, setting verbose=1 adds a details section to the response to display the query parameters as a JSON object.
     ///
     /// This is for checking how the GET parameters are translated into JSON, or that the POST parameters sent are as intended.
@@ -151,6 +951,87 @@ pub struct QueryParams {
     pub include: Vec<String>,
     /// Arrays of fields to exclude from the result. To be used in conjunction with the profile parameter to personalize the fields returned and streamline requests.
     pub exclude: Vec<String>,
+    /// Aggregations of matching items by field value, returned in the `embedded.facets` section of the response.
+    pub facets: Vec<QueryFacet>,
+}
+
+/// The serialized form of [`QueryParams`], shaped like the JSON body of `POST` requests.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct QueryBody {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    offset: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    profile: Option<QueryProfile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    preset: Option<QueryPreset>,
+    #[serde(default, skip_serializing_if = "QueryFields::is_empty")]
+    fields: QueryFields,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    query: Option<QueryQuery>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    filter: FilterGroup,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    sort: Vec<SortDescriptor>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    facets: Vec<QueryFacet>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    verbose: Option<bool>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct QueryFields {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    include: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    exclude: Vec<String>,
+}
+
+impl QueryFields {
+    fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+}
+
+impl From<QueryBody> for QueryParams {
+    fn from(body: QueryBody) -> Self {
+        Self {
+            query: body.query,
+            filter: body.filter,
+            verbose: body.verbose,
+            limit: body.limit,
+            offset: body.offset,
+            sort: body.sort,
+            profile: body.profile,
+            preset: body.preset,
+            include: body.fields.include,
+            exclude: body.fields.exclude,
+            facets: body.facets,
+        }
+    }
+}
+
+impl From<QueryParams> for QueryBody {
+    fn from(params: QueryParams) -> Self {
+        Self {
+            limit: params.limit,
+            offset: params.offset,
+            profile: params.profile,
+            preset: params.preset,
+            fields: QueryFields {
+                include: params.include,
+                exclude: params.exclude,
+            },
+            query: params.query,
+            filter: params.filter,
+            sort: params.sort,
+            facets: params.facets,
+            verbose: params.verbose,
+        }
+    }
 }
 
 impl QueryParams {
@@ -164,13 +1045,21 @@ impl QueryParams {
         self
     }
 
-    pub fn filter(mut self, filter: QueryFilter) -> Self {
-        self.filter.push(filter);
+    pub fn filter(mut self, filter: impl Into<Filter>) -> Self {
+        self.filter.conditions.push(filter.into());
+        self
+    }
+
+    pub fn filters(mut self, filters: impl IntoIterator<Item = impl Into<Filter>>) -> Self {
+        self.filter
+            .conditions
+            .extend(filters.into_iter().map(Into::into));
         self
     }
 
-    pub fn filters(mut self, filters: Vec<QueryFilter>) -> Self {
-        self.filter.extend(filters);
+    /// Set how the top-level filters are combined. Can be AND or OR.
+    pub fn filter_operator(mut self, operator: FilterOperator) -> Self {
+        self.filter.operator = Some(operator);
         self
     }
 
@@ -213,6 +1102,23 @@ impl QueryParams {
         self.exclude.extend(exclude);
         self
     }
+
+    /// Include the given fields of a resource, e.g. `[ReportField::Title]`.
+    pub fn include_fields(mut self, fields: impl IntoIterator<Item = impl ResourceField>) -> Self {
+        self.include.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Exclude the given fields of a resource, e.g. `[ReportField::Body]`.
+    pub fn exclude_fields(mut self, fields: impl IntoIterator<Item = impl ResourceField>) -> Self {
+        self.exclude.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn facet(mut self, facet: QueryFacet) -> Self {
+        self.facets.push(facet);
+        self
+    }
 }
 
 impl QueryParams {
@@ -220,68 +1126,653 @@ impl QueryParams {
     pub(crate) fn apply_to_url(&self, url: &mut Url) {
         let mut qp = url.query_pairs_mut();
 
-        if let Some(v) = self.verbose {
-            qp.append_pair("verbose", if v { "1" } else { "0" });
-        }
-        if let Some(l) = self.limit {
-            qp.append_pair("limit", &l.to_string());
-        }
-        if let Some(o) = self.offset {
-            qp.append_pair("offset", &o.to_string());
-        }
-        if let Some(profile) = &self.profile {
-            qp.append_pair("profile", &profile.to_string());
-        }
-        if let Some(preset) = &self.preset {
-            qp.append_pair("preset", &preset.to_string());
-        }
+        if let Some(v) = self.verbose {
+            qp.append_pair("verbose", if v { "1" } else { "0" });
+        }
+        if let Some(l) = self.limit {
+            qp.append_pair("limit", &l.to_string());
+        }
+        if let Some(o) = self.offset {
+            qp.append_pair("offset", &o.to_string());
+        }
+        if let Some(profile) = &self.profile {
+            qp.append_pair("profile", &profile.to_string());
+        }
+        if let Some(preset) = &self.preset {
+            qp.append_pair("preset", &preset.to_string());
+        }
+
+        for inc in &self.include {
+            qp.append_pair("fields[include][]", inc);
+        }
+        for exc in &self.exclude {
+            qp.append_pair("fields[exclude][]", exc);
+        }
+
+        if let Some(query) = &self.query {
+            qp.append_pair("query[value]", &query.value);
+            for (j, field) in query.fields.iter().enumerate() {
+                qp.append_pair(&format!("query[fields][{j}]"), field);
+            }
+            if let Some(op) = &query.operator {
+                qp.append_pair("query[operator]", &op.to_string());
+            }
+        }
+
+        if !self.filter.is_empty() {
+            let mut pairs = Vec::new();
+            self.filter.append_pairs("filter", &mut pairs);
+            qp.extend_pairs(pairs);
+        }
+
+        for s in &self.sort {
+            qp.append_pair("sort[]", &format!("{}:{}", s.field, s.direction));
+        }
+
+        let mut pairs = Vec::new();
+        for (i, facet) in self.facets.iter().enumerate() {
+            facet.append_pairs(&format!("facets[{i}]"), &mut pairs);
+        }
+        qp.extend_pairs(pairs);
+    }
+
+    /// Serialize the parameters to the JSON payload accepted by the API for `POST` requests.
+    ///
+    /// The payload mirrors the GET encoding of the parameters, except for `verbose`,
+    /// which the API only reads from the URL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reliefweb::QueryParams;
+    ///
+    /// let body = QueryParams::new().limit(5).include(vec!["title".to_string()]).to_json();
+    /// assert_eq!(body, serde_json::json!({ "limit": 5, "fields": { "include": ["title"] } }));
+    /// ```
+    pub fn to_json(&self) -> Value {
+        let mut body = Map::new();
+
+        if let Some(l) = self.limit {
+            body.insert("limit".into(), json!(l));
+        }
+        if let Some(o) = self.offset {
+            body.insert("offset".into(), json!(o));
+        }
+        if let Some(profile) = &self.profile {
+            body.insert("profile".into(), json!(profile.to_string()));
+        }
+        if let Some(preset) = &self.preset {
+            body.insert("preset".into(), json!(preset.to_string()));
+        }
+
+        if !self.include.is_empty() || !self.exclude.is_empty() {
+            let mut fields = Map::new();
+            if !self.include.is_empty() {
+                fields.insert("include".into(), json!(self.include));
+            }
+            if !self.exclude.is_empty() {
+                fields.insert("exclude".into(), json!(self.exclude));
+            }
+            body.insert("fields".into(), Value::Object(fields));
+        }
+
+        if let Some(query) = &self.query {
+            let mut q = Map::new();
+            q.insert("value".into(), json!(query.value));
+            if !query.fields.is_empty() {
+                q.insert("fields".into(), json!(query.fields));
+            }
+            if let Some(op) = &query.operator {
+                q.insert("operator".into(), json!(op.to_string()));
+            }
+            body.insert("query".into(), Value::Object(q));
+        }
+
+        if !self.filter.is_empty() {
+            body.insert("filter".into(), self.filter.to_json());
+        }
+
+        if !self.sort.is_empty() {
+            let sort: Vec<_> = self
+                .sort
+                .iter()
+                .map(|s| format!("{}:{}", s.field, s.direction))
+                .collect();
+            body.insert("sort".into(), json!(sort));
+        }
+
+        if !self.facets.is_empty() {
+            let facets = self.facets.iter().map(QueryFacet::to_json).collect();
+            body.insert("facets".into(), Value::Array(facets));
+        }
+
+        Value::Object(body)
+    }
+}
+
+impl QueryParams {
+    /// Parse the parameters of a ReliefWeb API URL, such as one copied from a browser.
+    ///
+    /// `url` can be a full URL or just its query string. The encoding is the one produced for `GET` requests,
+    /// e.g. `filter[conditions][0][field]=country&filter[conditions][0][value]=Sudan`.
+    /// The `appname` parameter is ignored, while any other unknown parameter is rejected.
+    ///
+    /// Parsing inverts the encoding of parameters accepted by [`QueryParams::validate`] exactly. Filters it rejects,
+    /// such as empty lists of values, ranges without bounds and groups without conditions, have nothing to encode.
+    ///
+    /// A filter value given as `[value]=Sudan` is parsed as [`FilterValue::Value`], while values given as a list,
+    /// e.g. `[value][]=Sudan` or `[value][0]=Sudan`, are parsed as [`FilterValue::Values`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reliefweb::{QueryParams, QueryProfile};
+    ///
+    /// let params = QueryParams::from_url(
+    ///     "https://api.reliefweb.int/v2/reports?appname=my_app&limit=5&profile=list&sort[]=date.created:desc",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(params.limit, Some(5));
+    /// assert_eq!(params.profile, Some(QueryProfile::List));
+    /// ```
+    pub fn from_url(url: &str) -> Result<Self> {
+        let query = match url.split_once('?') {
+            Some((_, query)) => query,
+            None if url.contains("://") => "",
+            None => url,
+        };
+
+        let mut root = Node::default();
+        for (key, value) in
+            url::form_urlencoded::parse(query.split('#').next().unwrap_or_default().as_bytes())
+        {
+            let (name, rest) = key.split_once('[').unwrap_or((&key, ""));
+            let mut path = vec![name];
+            if !rest.is_empty() {
+                let inner = rest.strip_suffix(']').ok_or_else(|| invalid_key(&key))?;
+                path.extend(inner.split("]["));
+            }
+            root.insert(&path, value.into_owned());
+        }
+
+        let mut params = QueryParams::new();
+        for (name, node) in root.entries()? {
+            match name.as_str() {
+                "appname" => {}
+                "verbose" => params.verbose = Some(parse_bool(node.single(name)?)?),
+                "limit" => params.limit = Some(parse_number(node.single(name)?)?),
+                "offset" => params.offset = Some(parse_number(node.single(name)?)?),
+                "profile" => {
+                    params.profile = Some(match node.single(name)? {
+                        "minimal" => QueryProfile::Minimal,
+                        "full" => QueryProfile::Full,
+                        "list" => QueryProfile::List,
+                        other => return Err(invalid_value(name, other)),
+                    })
+                }
+                "preset" => {
+                    params.preset = Some(match node.single(name)? {
+                        "minimal" => QueryPreset::Minimal,
+                        "latest" => QueryPreset::Latest,
+                        "analysis" => QueryPreset::Analysis,
+                        other => return Err(invalid_value(name, other)),
+                    })
+                }
+                "fields" => {
+                    for (kind, node) in node.entries()? {
+                        match kind.as_str() {
+                            "include" => params.include = node.list()?,
+                            "exclude" => params.exclude = node.list()?,
+                            _ => return Err(invalid_key(&format!("fields[{kind}]"))),
+                        }
+                    }
+                }
+                "query" => {
+                    let mut query = QueryQuery::default();
+                    for (key, node) in node.entries()? {
+                        match key.as_str() {
+                            "value" => query.value = node.single("query[value]")?.to_string(),
+                            "fields" => query.fields = node.list()?,
+                            "operator" => query.operator = Some(parse_operator(node)?),
+                            _ => return Err(invalid_key(&format!("query[{key}]"))),
+                        }
+                    }
+                    params.query = Some(query);
+                }
+                "filter" => params.filter = parse_group(node)?,
+                "sort" => {
+                    for sort in node.list()? {
+                        params.sort.push(SortDescriptor::try_from(sort)?);
+                    }
+                }
+                "facets" => {
+                    for (_, node) in node.indexed()? {
+                        params.facets.push(parse_facet(node)?);
+                    }
+                }
+                _ => return Err(invalid_key(name)),
+            }
+        }
+        Ok(params)
+    }
+}
+
+impl std::str::FromStr for QueryParams {
+    type Err = Error;
+
+    /// Parse the parameters of a ReliefWeb API URL, like [`QueryParams::from_url`].
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_url(s)
+    }
+}
+
+/// The tree of bracketed URL parameters, e.g. `filter[conditions][0][field]`, in order of appearance.
+#[derive(Default)]
+struct Node {
+    values: Vec<String>,
+    /// Whether values were given as a list, e.g. `key[]=a`, rather than as `key=a`.
+    listed: bool,
+    children: Vec<(String, Node)>,
+}
+
+impl Node {
+    fn insert(&mut self, path: &[&str], value: String) {
+        match path {
+            [] => self.values.push(value),
+            [""] => {
+                self.listed = true;
+                self.values.push(value);
+            }
+            [key, rest @ ..] => {
+                let index = match self.children.iter().position(|(k, _)| k == key) {
+                    Some(index) => index,
+                    None => {
+                        self.children.push((key.to_string(), Node::default()));
+                        self.children.len() - 1
+                    }
+                };
+                self.children[index].1.insert(rest, value);
+            }
+        }
+    }
+
+    /// Returns the named children of the node, which must not have values of its own.
+    fn entries(&self) -> Result<impl Iterator<Item = (&String, &Node)>> {
+        if !self.values.is_empty() {
+            return Err(Error::InvalidParameters(format!(
+                "unexpected value `{}`",
+                self.values[0]
+            )));
+        }
+        Ok(self.children.iter().map(|(k, v)| (k, v)))
+    }
+
+    /// Returns the children of the node keyed by index, e.g. `[conditions][0]`, sorted by index.
+    fn indexed(&self) -> Result<Vec<(usize, &Node)>> {
+        let mut entries = self
+            .entries()?
+            .map(|(key, node)| {
+                key.parse()
+                    .map(|i| (i, node))
+                    .map_err(|_| invalid_key(&format!("[{key}]")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        entries.sort_by_key(|(i, _)| *i);
+        Ok(entries)
+    }
+
+    /// Returns the single value of the node.
+    fn single(&self, name: &str) -> Result<&str> {
+        match (self.values.as_slice(), self.children.is_empty()) {
+            ([value], true) => Ok(value),
+            _ => Err(Error::InvalidParameters(format!(
+                "expected a single value for `{name}`"
+            ))),
+        }
+    }
+
+    /// Returns the values of the node, given either as `key[]=a&key[]=b` or as `key[0]=a&key[1]=b`.
+    fn list(&self) -> Result<Vec<String>> {
+        if self.children.is_empty() {
+            return Ok(self.values.clone());
+        }
+        self.indexed()?
+            .into_iter()
+            .map(|(i, node)| node.single(&format!("[{i}]")).map(str::to_string))
+            .collect()
+    }
+
+    fn get(&self, key: &str) -> Option<&Node> {
+        self.children.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+}
+
+fn parse_filter(node: &Node) -> Result<Filter> {
+    if node.get("field").is_none() {
+        return parse_group(node).map(Filter::Group);
+    }
+    let mut condition = QueryFilter::new("", FilterValue::Exists);
+    for (key, node) in node.entries()? {
+        match key.as_str() {
+            "field" => condition.field = node.single("field")?.to_string(),
+            "value" => {
+                condition.value = if node.children.is_empty() {
+                    match node.values.as_slice() {
+                        [value] if !node.listed => FilterValue::Value(value.clone()),
+                        values => FilterValue::Values(values.to_vec()),
+                    }
+                } else if node
+                    .children
+                    .iter()
+                    .all(|(key, _)| key.parse::<usize>().is_ok())
+                {
+                    FilterValue::Values(node.list()?)
+                } else {
+                    let mut range = (None, None);
+                    for (bound, node) in node.entries()? {
+                        match bound.as_str() {
+                            "from" => range.0 = Some(node.single("from")?.to_string()),
+                            "to" => range.1 = Some(node.single("to")?.to_string()),
+                            _ => return Err(invalid_key(&format!("[value][{bound}]"))),
+                        }
+                    }
+                    FilterValue::Range {
+                        from: range.0,
+                        to: range.1,
+                    }
+                }
+            }
+            "operator" => condition.operator = Some(parse_operator(node)?),
+            "negate" => condition.negate = parse_bool(node.single("negate")?)?,
+            _ => return Err(invalid_key(&format!("[{key}]"))),
+        }
+    }
+    Ok(Filter::Condition(condition))
+}
+
+fn parse_group(node: &Node) -> Result<FilterGroup> {
+    let mut group = FilterGroup::default();
+    for (key, node) in node.entries()? {
+        match key.as_str() {
+            "operator" => group.operator = Some(parse_operator(node)?),
+            "negate" => group.negate = parse_bool(node.single("negate")?)?,
+            "conditions" => {
+                for (_, node) in node.indexed()? {
+                    group.conditions.push(parse_filter(node)?);
+                }
+            }
+            _ => return Err(invalid_key(&format!("[{key}]"))),
+        }
+    }
+    Ok(group)
+}
+
+fn parse_facet_sort(sort: &str) -> Result<(FacetSortBy, SortDirection)> {
+    let (by, direction) = sort
+        .split_once(':')
+        .ok_or_else(|| invalid_value("sort", sort))?;
+    let by = match by {
+        "count" => FacetSortBy::Count,
+        "value" => FacetSortBy::Value,
+        other => return Err(invalid_value("sort", other)),
+    };
+    Ok((by, parse_direction(direction)?))
+}
+
+/// Serializes the sort of a facet like in the API, as `by:direction`, e.g. `"count:desc"`.
+mod facet_sort {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error as _};
+
+    use super::{FacetSortBy, SortDirection, parse_facet_sort};
+
+    pub fn serialize<S: Serializer>(
+        sort: &Option<(FacetSortBy, SortDirection)>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match sort {
+            Some((by, direction)) => serializer.collect_str(&format_args!("{by}:{direction}")),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<(FacetSortBy, SortDirection)>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|sort| parse_facet_sort(&sort).map_err(D::Error::custom))
+            .transpose()
+    }
+}
+
+fn parse_facet(node: &Node) -> Result<QueryFacet> {
+    let mut facet = QueryFacet::new("");
+    for (key, node) in node.entries()? {
+        match key.as_str() {
+            "field" => facet.field = node.single("field")?.to_string(),
+            "name" => facet.name = Some(node.single("name")?.to_string()),
+            "limit" => facet.limit = Some(parse_number(node.single("limit")?)?),
+            "sort" => facet.sort = Some(parse_facet_sort(node.single("sort")?)?),
+            "interval" => {
+                facet.interval = Some(match node.single("interval")? {
+                    "year" => FacetInterval::Year,
+                    "month" => FacetInterval::Month,
+                    "week" => FacetInterval::Week,
+                    "day" => FacetInterval::Day,
+                    other => return Err(invalid_value("interval", other)),
+                })
+            }
+            "filter" => facet.filter = Some(parse_filter(node)?),
+            "scope" => {
+                facet.scope = Some(match node.single("scope")? {
+                    "query" => FacetScope::Query,
+                    "global" => FacetScope::Global,
+                    other => return Err(invalid_value("scope", other)),
+                })
+            }
+            _ => return Err(invalid_key(&format!("facets[{key}]"))),
+        }
+    }
+    Ok(facet)
+}
+
+fn parse_operator(node: &Node) -> Result<FilterOperator> {
+    match node.single("operator")? {
+        "AND" => Ok(FilterOperator::AND),
+        "OR" => Ok(FilterOperator::OR),
+        other => Err(invalid_value("operator", other)),
+    }
+}
+
+fn parse_direction(direction: &str) -> Result<SortDirection> {
+    match direction {
+        "asc" => Ok(SortDirection::Asc),
+        "desc" => Ok(SortDirection::Desc),
+        other => Err(invalid_value("sort", other)),
+    }
+}
+
+fn parse_bool(value: &str) -> Result<bool> {
+    match value {
+        "1" | "true" => Ok(true),
+        "0" | "false" => Ok(false),
+        other => Err(invalid_value("flag", other)),
+    }
+}
+
+fn parse_number(value: &str) -> Result<u32> {
+    value.parse().map_err(|_| invalid_value("number", value))
+}
+
+fn invalid_key(key: &str) -> Error {
+    Error::InvalidParameters(format!("unknown parameter `{key}`"))
+}
+
+fn invalid_value(name: &str, value: &str) -> Error {
+    Error::InvalidParameters(format!("invalid {name} `{value}`"))
+}
+
+impl QueryParams {
+    /// Check the parameters for mistakes the API would reject or silently ignore.
+    ///
+    /// This rejects a `limit` over [`MAX_PAGE_SIZE`], an empty full-text query, filter conditions without a value,
+    /// filter groups without conditions, and fields both included and excluded. All the problems found are returned
+    /// at once.
+    ///
+    /// This is run automatically by [`ResourceEndpoint::list`](crate::ResourceEndpoint::list).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reliefweb::{Error, QueryParams, QueryQuery, ValidationError};
+    ///
+    /// let params = QueryParams::new().limit(5000).query(QueryQuery::new(""));
+    /// let Err(Error::Validation(errors)) = params.validate() else { panic!() };
+    /// assert_eq!(errors[1], ValidationError::EmptyQuery);
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        into_result(self.validation_errors())
+    }
+
+    /// Check that the fields named in the parameters exist for `resource` (e.g. `reports` or `references/themes`),
+    /// in includes and excludes, full-text query fields, filters, sorts and facets.
+    ///
+    /// Unlike [`QueryParams::validate`], this is only run by [`ResourceEndpoint::list`](crate::ResourceEndpoint::list)
+    /// when enabled with [`ClientBuilder::validate_fields`](crate::ClientBuilder::validate_fields), as the field lists
+    /// of this client may lag behind the API. Fields are not checked for resources unknown to this client. Boosts
+    /// such as `title^5` and the `.exact` suffix are accepted on known fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reliefweb::{Error, QueryParams, ValidationError};
+    ///
+    /// let params = QueryParams::new().include(vec!["title".into(), "primary_country.iso".into()]);
+    /// let Err(Error::Validation(errors)) = params.validate_fields_for("reports") else { panic!() };
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    pub fn validate_fields_for(&self, resource: &str) -> Result<()> {
+        into_result(self.unknown_fields(resource))
+    }
+
+    /// Check the parameters like [`QueryParams::validate`] and [`QueryParams::validate_fields_for`] at once.
+    pub(crate) fn validate_for(&self, resource: &str) -> Result<()> {
+        let mut errors = self.validation_errors();
+        errors.extend(self.unknown_fields(resource));
+        into_result(errors)
+    }
+
+    fn validation_errors(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
 
-        for inc in &self.include {
-            qp.append_pair("fields[include][]", inc);
+        if let Some(limit) = self.limit.filter(|&limit| limit > MAX_PAGE_SIZE) {
+            errors.push(ValidationError::LimitOutOfRange {
+                limit,
+                max: MAX_PAGE_SIZE,
+            });
         }
-        for exc in &self.exclude {
-            qp.append_pair("fields[exclude][]", exc);
+        if self
+            .query
+            .as_ref()
+            .is_some_and(|query| query.value.trim().is_empty())
+        {
+            errors.push(ValidationError::EmptyQuery);
         }
 
-        if let Some(query) = &self.query {
-            qp.append_pair(&format!("query[value]"), &query.value);
-            for (j, field) in query.fields.iter().enumerate() {
-                qp.append_pair(&format!("query[fields][{j}]"), field);
-            }
-            if let Some(op) = &query.operator {
-                qp.append_pair(&format!("query[operator]"), &op.to_string());
+        for condition in &self.conditions() {
+            let empty = match &condition.value {
+                FilterValue::Value(_) | FilterValue::Exists => false,
+                FilterValue::Values(values) => values.is_empty(),
+                FilterValue::Range { from, to } => from.is_none() && to.is_none(),
+            };
+            if empty {
+                errors.push(ValidationError::EmptyFilterValue {
+                    field: condition.field.clone(),
+                });
             }
         }
 
-        if !self.filter.is_empty() {
-            if self.filter.iter().any(|f| f.operator.is_some()) {
-                let top_op = self
-                    .filter
+        // The root group may only be left empty when it is not set at all.
+        let empty_root = usize::from(
+            self.filter.conditions.is_empty()
+                && (self.filter.operator.is_some() || self.filter.negate),
+        );
+        let empty_groups = empty_root
+            + self
+                .filter
+                .conditions
+                .iter()
+                .chain(self.facets.iter().filter_map(|f| f.filter.as_ref()))
+                .map(Filter::empty_groups)
+                .sum::<usize>();
+        errors.extend(std::iter::repeat_n(
+            ValidationError::EmptyFilterGroup,
+            empty_groups,
+        ));
+
+        for field in self.include.iter().filter(|f| self.exclude.contains(f)) {
+            errors.push(ValidationError::IncludeExcludeConflict {
+                field: field.clone(),
+            });
+        }
+
+        errors
+    }
+
+    fn unknown_fields(&self, resource: &str) -> Vec<ValidationError> {
+        let conditions = self.conditions();
+        let fields = self
+            .include
+            .iter()
+            .map(|f| ("include", f))
+            .chain(self.exclude.iter().map(|f| ("exclude", f)))
+            .chain(
+                self.query
                     .iter()
-                    .filter_map(|f| f.operator.as_ref())
-                    .next()
-                    .unwrap()
-                    .to_string();
-                qp.append_pair("filter[operator]", &top_op);
-            }
-            for (i, f) in self.filter.iter().enumerate() {
-                qp.append_pair(&format!("filter[conditions][{i}][field]"), &f.field);
-                qp.append_pair(&format!("filter[conditions][{i}][value][]"), &f.value);
-                if f.negate {
-                    qp.append_pair(&format!("filter[conditions][{i}][negate]"), "1");
-                }
-                if let Some(op) = &f.operator {
-                    qp.append_pair(
-                        &format!("filter[conditions][{i}][operator]"),
-                        &op.to_string(),
-                    );
-                }
+                    .flat_map(|q| &q.fields)
+                    .map(|f| ("query", f)),
+            )
+            .chain(conditions.iter().map(|c| ("filter", &c.field)))
+            .chain(
+                self.sort
+                    .iter()
+                    .filter(|s| s.field != "score")
+                    .map(|s| ("sort", &s.field)),
+            )
+            .chain(self.facets.iter().map(|f| ("facets", &f.field)));
+
+        fields
+            .filter(|(_, field)| is_known_field(resource, field) == Some(false))
+            .map(|(location, field)| ValidationError::UnknownField {
+                resource: resource.to_string(),
+                field: field.clone(),
+                location,
+            })
+            .collect()
+    }
+
+    /// Returns the filter conditions of the parameters, including the ones of facet filters.
+    fn conditions(&self) -> Vec<&QueryFilter> {
+        let mut conditions = Vec::new();
+        self.filter.collect_conditions(&mut conditions);
+        for facet in &self.facets {
+            if let Some(filter) = &facet.filter {
+                filter.collect_conditions(&mut conditions);
             }
         }
+        conditions
+    }
+}
 
-        for s in &self.sort {
-            qp.append_pair("sort[]", &format!("{}:{}", s.field, s.direction));
-        }
+/// Returns `true` if `value` is the default, to leave it out when serializing.
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+fn into_result(errors: Vec<ValidationError>) -> Result<()> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Validation(errors))
     }
 }
 
@@ -332,6 +1823,7 @@ mod tests {
             })
         );
         assert_eq!(qp.filter.len(), 1);
+        assert_eq!(qp.filter.operator, None);
         assert_eq!(qp.sort.len(), 1);
     }
 
@@ -365,6 +1857,139 @@ mod tests {
         assert!(query.contains(&("fields[exclude][]".into(), "internal".into())));
     }
 
+    #[test]
+    fn test_field_enums() {
+        use crate::fields::{job::JobField, report::ReportField};
+
+        let mut url = Url::parse("https://example.com/api").unwrap();
+        QueryParams::new()
+            .query(QueryQuery::new("flood").fields([ReportField::Title, ReportField::Body]))
+            .filter(Filter::condition(ReportField::PrimaryCountryIso3, "sdn"))
+            .sort(vec![SortDescriptor::desc(ReportField::DateCreated)])
+            .include_fields([ReportField::Title])
+            .exclude(vec!["body".into()])
+            .facet(QueryFacet::new(ReportField::SourceShortname))
+            .apply_to_url(&mut url);
+
+        let query: Vec<(_, _)> = url.query_pairs().collect();
+        assert!(query.contains(&("query[fields][1]".into(), "body".into())));
+        assert!(query.contains(&(
+            "filter[conditions][0][field]".into(),
+            "primary_country.iso3".into()
+        )));
+        assert!(query.contains(&("sort[]".into(), "date.created:desc".into())));
+        assert!(query.contains(&("fields[include][]".into(), "title".into())));
+        assert!(query.contains(&("fields[exclude][]".into(), "body".into())));
+        assert!(query.contains(&("facets[0][field]".into(), "source.shortname".into())));
+
+        assert_eq!(
+            ReportField::from_name("primary_country.iso3"),
+            Some(ReportField::PrimaryCountryIso3)
+        );
+        assert_eq!(ReportField::from_name("primary_country.iso"), None);
+        assert_eq!(JobField::from_name("ocha_product"), None);
+        for field in JobField::ALL {
+            assert_eq!(JobField::from_name(field.as_str()), Some(*field));
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(QueryParams::new().limit(MAX_PAGE_SIZE).validate().is_ok());
+
+        let params = QueryParams::new()
+            .limit(1001)
+            .query(QueryQuery::new("  "))
+            .filter(Filter::or([
+                Filter::any_of("country.iso3", Vec::<String>::new()),
+                Filter::condition(
+                    "date.created",
+                    FilterValue::Range {
+                        from: None,
+                        to: None,
+                    },
+                ),
+                Filter::and(Vec::<Filter>::new()),
+            ]))
+            .include(vec!["title".into(), "body".into()])
+            .exclude(vec!["body".into()]);
+        let Err(Error::Validation(errors)) = params.validate() else {
+            panic!("expected validation errors");
+        };
+        assert_eq!(
+            errors,
+            [
+                ValidationError::LimitOutOfRange {
+                    limit: 1001,
+                    max: MAX_PAGE_SIZE
+                },
+                ValidationError::EmptyQuery,
+                ValidationError::EmptyFilterValue {
+                    field: "country.iso3".to_string()
+                },
+                ValidationError::EmptyFilterValue {
+                    field: "date.created".to_string()
+                },
+                ValidationError::EmptyFilterGroup,
+                ValidationError::IncludeExcludeConflict {
+                    field: "body".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_fields_for_resource() {
+        let params = QueryParams::new()
+            .include(vec!["title".into(), "primary_country.iso".into()])
+            .query(QueryQuery::new("cholera").fields(["title^5", "body"]))
+            .filter(!Filter::condition("theme.name", "Health"))
+            .filter(Filter::condition("country.name.exact", "Chad"))
+            .sort(vec![
+                SortDescriptor::desc("score"),
+                SortDescriptor::asc("date.closing"),
+            ])
+            .facet(QueryFacet::new("source.shortname").filter(Filter::exists("headline")));
+
+        assert!(params.validate().is_ok());
+        assert!(params.validate_fields_for("unknown").is_ok());
+        let Err(Error::Validation(errors)) = params.validate_fields_for("reports") else {
+            panic!("expected validation errors");
+        };
+        let unknown: Vec<_> = errors
+            .iter()
+            .map(|e| match e {
+                ValidationError::UnknownField {
+                    field, location, ..
+                } => (field.as_str(), *location),
+                other => panic!("unexpected error {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            unknown,
+            [("primary_country.iso", "include"), ("date.closing", "sort")]
+        );
+
+        let Err(Error::Validation(errors)) = params.validate_fields_for("jobs") else {
+            panic!("expected validation errors");
+        };
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[1],
+            ValidationError::UnknownField {
+                resource: "jobs".to_string(),
+                field: "headline".to_string(),
+                location: "filter"
+            }
+        );
+        assert!(
+            QueryParams::new()
+                .include(vec!["name".into(), "code".into()])
+                .validate_fields_for("references/languages")
+                .is_ok()
+        );
+    }
+
     #[test]
     fn test_apply_to_url_queries() {
         let mut url = Url::parse("https://example.com/api").unwrap();
@@ -397,13 +2022,250 @@ mod tests {
 
         qp.apply_to_url(&mut url);
 
+        // The operator of a condition only applies to its values, and no longer sets the top-level operator.
+        let query: Vec<(_, _)> = url.query_pairs().collect();
+        assert!(!query.iter().any(|(key, _)| key == "filter[operator]"));
+        assert!(query.contains(&("filter[conditions][0][operator]".into(), "OR".into())));
+        assert!(query.contains(&("filter[conditions][0][field]".into(), "status".into())));
+        assert!(query.contains(&("filter[conditions][0][value]".into(), "active".into())));
+        assert!(query.contains(&("filter[conditions][0][negate]".into(), "1".into())));
+
+        // The top-level operator is set with `filter_operator` instead.
+        let mut url = Url::parse("https://example.com/api").unwrap();
+        qp.filter_operator(FilterOperator::OR)
+            .apply_to_url(&mut url);
+
         let query: Vec<(_, _)> = url.query_pairs().collect();
         assert!(query.contains(&("filter[operator]".into(), "OR".into())));
         assert!(query.contains(&("filter[conditions][0][field]".into(), "status".into())));
-        assert!(query.contains(&("filter[conditions][0][value][]".into(), "active".into())));
+        assert!(query.contains(&("filter[conditions][0][value]".into(), "active".into())));
         assert!(query.contains(&("filter[conditions][0][negate]".into(), "1".into())));
     }
 
+    #[test]
+    fn test_apply_to_url_nested_filters() {
+        let mut url = Url::parse("https://example.com/api").unwrap();
+
+        let qp = QueryParams::new()
+            .filter_operator(FilterOperator::AND)
+            .filter(Filter::or([
+                Filter::condition("country", "Syria"),
+                Filter::condition("country", "Iraq"),
+            ]))
+            .filter(!Filter::condition("format", "Map"));
+
+        qp.apply_to_url(&mut url);
+
+        let query: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+        let expected = [
+            ("filter[operator]", "AND"),
+            ("filter[conditions][0][operator]", "OR"),
+            ("filter[conditions][0][conditions][0][field]", "country"),
+            ("filter[conditions][0][conditions][0][value]", "Syria"),
+            ("filter[conditions][0][conditions][1][field]", "country"),
+            ("filter[conditions][0][conditions][1][value]", "Iraq"),
+            ("filter[conditions][1][field]", "format"),
+            ("filter[conditions][1][value]", "Map"),
+            ("filter[conditions][1][negate]", "1"),
+        ];
+        assert_eq!(
+            query,
+            expected
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_apply_to_url_filter_values() {
+        let mut url = Url::parse("https://example.com/api").unwrap();
+
+        let qp = QueryParams::new()
+            .filter(Filter::condition("id", FilterValue::between(10, 20)))
+            .filter(Filter::condition(
+                "date.created",
+                FilterValue::at_most("2024-01-01T00:00:00+00:00"),
+            ))
+            .filter(Filter::exists("image"));
+
+        qp.apply_to_url(&mut url);
+
+        let query: Vec<(_, _)> = url.query_pairs().collect();
+        assert!(query.contains(&("filter[conditions][0][value][from]".into(), "10".into())));
+        assert!(query.contains(&("filter[conditions][0][value][to]".into(), "20".into())));
+        assert!(query.contains(&(
+            "filter[conditions][1][value][to]".into(),
+            "2024-01-01T00:00:00+00:00".into()
+        )));
+        assert!(
+            !query
+                .iter()
+                .any(|(k, _)| k == "filter[conditions][1][value][from]")
+        );
+        assert!(query.contains(&("filter[conditions][2][field]".into(), "image".into())));
+        assert!(
+            !query
+                .iter()
+                .any(|(k, _)| k.starts_with("filter[conditions][2][value]"))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_range_bounds() {
+        use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+        let utc = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 0).unwrap();
+        assert_eq!(utc.to_bound(), "2024-03-01T12:30:00+00:00");
+        let cet = FixedOffset::east_opt(3600)
+            .unwrap()
+            .with_ymd_and_hms(2024, 3, 1, 12, 30, 0)
+            .unwrap();
+        assert_eq!(cet.to_bound(), "2024-03-01T12:30:00+01:00");
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(
+            FilterValue::between(date, utc),
+            FilterValue::Range {
+                from: Some("2024-03-01T00:00:00+00:00".to_string()),
+                to: Some("2024-03-01T12:30:00+00:00".to_string()),
+            }
+        );
+        assert_eq!(
+            FilterValue::between(date, date),
+            FilterValue::Range {
+                from: Some("2024-03-01T00:00:00+00:00".to_string()),
+                to: Some("2024-03-01T23:59:59+00:00".to_string()),
+            }
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_range_bounds() {
+        use time::macros::{date, datetime};
+
+        assert_eq!(
+            datetime!(2024-03-01 12:30:00 +01:00).to_bound(),
+            "2024-03-01T12:30:00+01:00"
+        );
+        assert_eq!(
+            FilterValue::between(date!(2024 - 03 - 01), date!(2024 - 03 - 31)),
+            FilterValue::Range {
+                from: Some("2024-03-01T00:00:00+00:00".to_string()),
+                to: Some("2024-03-31T23:59:59+00:00".to_string()),
+            }
+        );
+        assert_eq!(
+            FilterValue::at_most(date!(2024 - 03 - 31)),
+            FilterValue::Range {
+                from: None,
+                to: Some("2024-03-31T23:59:59+00:00".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_apply_to_url_multi_value_filters() {
+        let mut url = Url::parse("https://example.com/api").unwrap();
+
+        let qp = QueryParams::new()
+            .filter(Filter::any_of("country.iso3", ["syr", "irq", "leb"]))
+            .filter(QueryFilter::all_of(
+                "theme.name",
+                vec!["Health".to_string(), "Food and Nutrition".to_string()],
+            ));
+
+        qp.apply_to_url(&mut url);
+
+        let query: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+        let expected = [
+            ("filter[conditions][0][field]", "country.iso3"),
+            ("filter[conditions][0][value][]", "syr"),
+            ("filter[conditions][0][value][]", "irq"),
+            ("filter[conditions][0][value][]", "leb"),
+            ("filter[conditions][0][operator]", "OR"),
+            ("filter[conditions][1][field]", "theme.name"),
+            ("filter[conditions][1][value][]", "Health"),
+            ("filter[conditions][1][value][]", "Food and Nutrition"),
+            ("filter[conditions][1][operator]", "AND"),
+        ];
+        assert_eq!(
+            query,
+            expected
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            qp.to_json()["filter"]["conditions"][0],
+            json!({ "field": "country.iso3", "value": ["syr", "irq", "leb"], "operator": "OR" })
+        );
+    }
+
+    #[test]
+    fn test_apply_to_url_facets() {
+        let mut url = Url::parse("https://example.com/api").unwrap();
+
+        let qp = QueryParams::new()
+            .facet(
+                QueryFacet::new("country.name")
+                    .name("countries")
+                    .limit(5)
+                    .sort(FacetSortBy::Count, SortDirection::Desc)
+                    .filter(Filter::condition("status", "current"))
+                    .scope(FacetScope::Global),
+            )
+            .facet(QueryFacet::new("date.created").interval(FacetInterval::Month));
+
+        qp.apply_to_url(&mut url);
+
+        let query: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+        let expected = [
+            ("facets[0][field]", "country.name"),
+            ("facets[0][name]", "countries"),
+            ("facets[0][limit]", "5"),
+            ("facets[0][sort]", "count:desc"),
+            ("facets[0][filter][field]", "status"),
+            ("facets[0][filter][value]", "current"),
+            ("facets[0][scope]", "global"),
+            ("facets[1][field]", "date.created"),
+            ("facets[1][interval]", "month"),
+        ];
+        assert_eq!(
+            query,
+            expected
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_negated_group() {
+        let filter = !Filter::and([Filter::condition("status", "archive")]);
+        match &filter {
+            Filter::Group(g) => {
+                assert!(g.negate);
+                assert_eq!(g.operator, Some(FilterOperator::AND));
+            }
+            Filter::Condition(_) => panic!("expected a group"),
+        }
+        assert_eq!(
+            !filter,
+            Filter::and([Filter::condition("status", "archive")])
+        );
+    }
+
     #[test]
     fn test_apply_to_url_sort() {
         let mut url = Url::parse("https://example.com/api").unwrap();
@@ -418,4 +2280,504 @@ mod tests {
         let query: Vec<(_, _)> = url.query_pairs().collect();
         assert!(query.contains(&("sort[]".into(), "date:desc".into())));
     }
+
+    /// Rebuilds the nested structure encoded by bracketed query pairs, e.g. `a[b][0]=c` into `{"a": {"b": ["c"]}}`.
+    fn pairs_to_json(url: &Url) -> Value {
+        let mut root = json!({});
+        for (key, value) in url.query_pairs() {
+            let mut segments = vec![key.split('[').next().unwrap().to_string()];
+            segments.extend(
+                key.split('[')
+                    .skip(1)
+                    .map(|s| s.trim_end_matches(']').to_string()),
+            );
+
+            let mut node = &mut root;
+            for (i, segment) in segments.iter().enumerate() {
+                let last = i == segments.len() - 1;
+                let next_is_index = segments
+                    .get(i + 1)
+                    .is_some_and(|s| s.is_empty() || s.parse::<usize>().is_ok());
+                let empty = || if next_is_index { json!([]) } else { json!({}) };
+                node = if segment.is_empty() {
+                    let arr = node.as_array_mut().unwrap();
+                    arr.push(if last { json!(value) } else { empty() });
+                    arr.last_mut().unwrap()
+                } else if let Ok(index) = segment.parse::<usize>() {
+                    let arr = node.as_array_mut().unwrap();
+                    if arr.len() <= index {
+                        arr.push(if last { json!(value) } else { empty() });
+                    }
+                    &mut arr[index]
+                } else {
+                    let obj = node.as_object_mut().unwrap();
+                    obj.entry(segment.clone())
+                        .or_insert_with(|| if last { json!(value) } else { empty() })
+                };
+            }
+        }
+        root
+    }
+
+    /// Turns every scalar into its GET string form, to compare JSON payloads with URL-encoded params.
+    fn stringify(value: Value) -> Value {
+        match value {
+            Value::Number(n) => json!(n.to_string()),
+            Value::Bool(b) => json!(if b { "1" } else { "0" }),
+            Value::Array(a) => Value::Array(a.into_iter().map(stringify).collect()),
+            Value::Object(o) => {
+                Value::Object(o.into_iter().map(|(k, v)| (k, stringify(v))).collect())
+            }
+            other => other,
+        }
+    }
+
+    #[test]
+    fn test_to_json_matches_url_encoding() {
+        let qp = QueryParams::new()
+            .limit(50)
+            .offset(10)
+            .profile(QueryProfile::Full)
+            .preset(QueryPreset::Latest)
+            .include(vec!["title".into(), "source.name".into()])
+            .exclude(vec!["body".into()])
+            .query(QueryQuery {
+                value: "cholera outbreak".into(),
+                fields: vec!["title".into(), "body".into()],
+                operator: Some(FilterOperator::AND),
+            })
+            .filter_operator(FilterOperator::AND)
+            .filters(vec![
+                Filter::or([
+                    Filter::condition("country", "Syria"),
+                    Filter::condition("country", "Iraq"),
+                ]),
+                !Filter::condition("format.name", "Map"),
+                Filter::condition(
+                    "date.created",
+                    FilterValue::between("2020-01-01", "2021-01-01"),
+                ),
+                Filter::exists("image"),
+                Filter::any_of("source.shortname", ["OCHA", "UNICEF"]),
+                Filter::from(QueryFilter {
+                    field: "theme".into(),
+                    value: "Health".into(),
+                    operator: Some(FilterOperator::AND),
+                    negate: false,
+                }),
+            ])
+            .sort(vec![
+                SortDescriptor {
+                    field: "date.created".into(),
+                    direction: SortDirection::Desc,
+                },
+                SortDescriptor {
+                    field: "id".into(),
+                    direction: SortDirection::Asc,
+                },
+            ])
+            .facet(
+                QueryFacet::new("country")
+                    .limit(10)
+                    .sort(FacetSortBy::Value, SortDirection::Asc)
+                    .filter(Filter::or([
+                        Filter::condition("status", "current"),
+                        Filter::condition("status", "alert"),
+                    ])),
+            )
+            .facet(QueryFacet::new("date.original").interval(FacetInterval::Year));
+
+        let mut url = Url::parse("https://example.com/api").unwrap();
+        qp.apply_to_url(&mut url);
+
+        assert_eq!(stringify(qp.to_json()), pairs_to_json(&url));
+    }
+
+    #[test]
+    fn test_to_json_empty() {
+        assert_eq!(QueryParams::new().verbose(true).to_json(), json!({}));
+    }
+
+    #[test]
+    fn test_from_url() {
+        let params: QueryParams =
+            "https://api.reliefweb.int/v2/reports?appname=rwint-user-0&profile=list&preset=latest"
+                .parse()
+                .unwrap();
+        assert_eq!(params.profile, Some(QueryProfile::List));
+        assert_eq!(params.preset, Some(QueryPreset::Latest));
+
+        let params = QueryParams::from_url(
+            "?query[value]=cholera&query[fields][]=title&query[operator]=AND\
+             &filter[operator]=AND&filter[conditions][0][field]=country.iso3\
+             &filter[conditions][0][value][]=sdn&filter[conditions][0][value][]=tcd\
+             &filter[conditions][1][field]=date.created&filter[conditions][1][value][from]=2024-01-01T00:00:00%2B00:00\
+             &filter[conditions][1][negate]=1&sort[]=date.created:desc&fields[include][]=title",
+        )
+        .unwrap();
+        assert_eq!(
+            params,
+            QueryParams::new()
+                .query(
+                    QueryQuery::new("cholera")
+                        .fields(["title"])
+                        .operator(FilterOperator::AND)
+                )
+                .filter_operator(FilterOperator::AND)
+                .filter(Filter::Condition(QueryFilter {
+                    operator: None,
+                    ..QueryFilter::any_of("country.iso3", ["sdn", "tcd"])
+                }))
+                .filter(!Filter::condition(
+                    "date.created",
+                    FilterValue::at_least("2024-01-01T00:00:00+00:00")
+                ))
+                .sort(vec![SortDescriptor::desc("date.created")])
+                .include(vec!["title".into()])
+        );
+    }
+
+    #[test]
+    fn test_from_url_filter_values() {
+        let value = |query: &str| {
+            let params =
+                QueryParams::from_url(&format!("filter[conditions][0][field]=country{query}"))
+                    .unwrap();
+            match &params.filter.conditions[0] {
+                Filter::Condition(condition) => condition.value.clone(),
+                Filter::Group(_) => panic!("expected a condition"),
+            }
+        };
+        assert_eq!(
+            value("&filter[conditions][0][value]=sdn"),
+            FilterValue::Value("sdn".into())
+        );
+        assert_eq!(
+            value("&filter[conditions][0][value][]=sdn"),
+            FilterValue::Values(vec!["sdn".into()])
+        );
+        assert_eq!(
+            value("&filter[conditions][0][value][0]=sdn"),
+            FilterValue::Values(vec!["sdn".into()])
+        );
+        assert_eq!(
+            value("&filter[conditions][0][value][1]=tcd&filter[conditions][0][value][0]=sdn"),
+            FilterValue::Values(vec!["sdn".into(), "tcd".into()])
+        );
+        assert_eq!(value(""), FilterValue::Exists);
+        assert!(
+            QueryParams::from_url(
+                "filter[conditions][0][field]=country&filter[conditions][0][value][]=sdn&filter[conditions][0][value][0]=tcd"
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_from_url_errors() {
+        for url in [
+            "limit=ten",
+            "profile=everything",
+            "sort[]=date.created",
+            "filter[conditions][first][field]=country",
+            "fields[hidden][]=title",
+            "unknown=1",
+            "filter[conditions][0][field]=a&filter[conditions][0][field]=b",
+        ] {
+            assert!(
+                matches!(QueryParams::from_url(url), Err(Error::InvalidParameters(_))),
+                "{url}"
+            );
+        }
+        assert_eq!(
+            QueryParams::from_url("https://api.reliefweb.int/v2/reports").unwrap(),
+            QueryParams::new()
+        );
+    }
+
+    #[test]
+    fn test_serde_schema() {
+        let params = QueryParams::new()
+            .query(QueryQuery::new("cholera").fields(["title"]))
+            .filter(Filter::condition("country.iso3", "hti"))
+            .filter(!Filter::any_of("format.name", vec!["Map", "Infographic"]))
+            .filter(Filter::condition(
+                "date.created",
+                FilterValue::at_least(2024),
+            ))
+            .filter(Filter::exists("image"))
+            .sort(vec![SortDescriptor::desc("date.created")])
+            .facet(
+                QueryFacet::new("source.shortname").sort(FacetSortBy::Count, SortDirection::Desc),
+            )
+            .profile(QueryProfile::List)
+            .limit(20);
+
+        let expected = json!({
+            "query": { "value": "cholera", "fields": ["title"] },
+            "filter": {
+                "conditions": [
+                    { "field": "country.iso3", "value": "hti" },
+                    { "field": "format.name", "value": ["Map", "Infographic"], "operator": "OR", "negate": true },
+                    { "field": "date.created", "value": { "from": "2024" } },
+                    { "field": "image" }
+                ]
+            },
+            "limit": 20,
+            "sort": ["date.created:desc"],
+            "profile": "list",
+            "facets": [{ "field": "source.shortname", "sort": "count:desc" }]
+        });
+        assert_eq!(serde_json::to_value(&params).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<QueryParams>(expected).unwrap(),
+            params
+        );
+
+        let toml = r#"
+            limit = 5
+            preset = "latest"
+            fields = { include = ["title"] }
+
+            [query]
+            value = "flood"
+            operator = "AND"
+
+            [filter]
+            operator = "OR"
+
+            [[filter.conditions]]
+            field = "country.iso3"
+            value = "npl"
+
+            [[filter.conditions]]
+            operator = "AND"
+            conditions = [{ field = "date.created", value = { from = "2024", to = "2025" } }]
+        "#;
+        let params: QueryParams = toml::from_str(toml).unwrap();
+        assert_eq!(
+            params,
+            QueryParams::new()
+                .query(QueryQuery::new("flood").operator(FilterOperator::AND))
+                .filter_operator(FilterOperator::OR)
+                .filter(Filter::condition("country.iso3", "npl"))
+                .filter(Filter::and([Filter::condition(
+                    "date.created",
+                    FilterValue::between(2024, 2025)
+                )]))
+                .preset(QueryPreset::Latest)
+                .include(vec!["title".into()])
+                .limit(5)
+        );
+
+        // Without `verbose`, the schema is the JSON body of POST requests.
+        let params = QueryParams::new()
+            .include(vec!["title".into()])
+            .filter(Filter::any_of("country.iso3", ["hti", "dom"]))
+            .filter(Filter::condition("format.name", "Map"))
+            .sort(vec![SortDescriptor::asc("title")])
+            .facet(QueryFacet::new("theme.name").sort(FacetSortBy::Value, SortDirection::Asc))
+            .offset(10);
+        assert_eq!(serde_json::to_value(&params).unwrap(), params.to_json());
+
+        for unknown in [
+            json!({ "filter": { "conditions": [{ "field": "country", "valeu": "Nepal" }] } }),
+            json!({ "filter": { "conditions": [{ "field": "date.created", "value": { "form": "2020" } }] } }),
+            json!({ "query": { "valeu": "cholera" } }),
+            json!({ "facets": [{ "field": "country", "limt": 5 }] }),
+            json!({ "limti": 10 }),
+            json!({ "fields": { "inclde": ["title"] } }),
+            json!({ "sort": ["date.created:up"] }),
+        ] {
+            assert!(serde_json::from_value::<QueryParams>(unknown).is_err());
+        }
+    }
+
+    mod round_trip {
+        use proptest::{collection::vec, option, prelude::*};
+
+        use super::*;
+
+        fn text() -> impl Strategy<Value = String> {
+            "[a-zA-Z0-9 _.:&=%+#?/\\[\\]-]{0,8}"
+        }
+
+        fn operator() -> impl Strategy<Value = FilterOperator> {
+            prop_oneof![Just(FilterOperator::AND), Just(FilterOperator::OR)]
+        }
+
+        fn direction() -> impl Strategy<Value = SortDirection> {
+            prop_oneof![Just(SortDirection::Asc), Just(SortDirection::Desc)]
+        }
+
+        /// Filter values accepted by [`QueryParams::validate`]: lists are not empty and ranges have a bound.
+        fn filter_value() -> impl Strategy<Value = FilterValue> {
+            prop_oneof![
+                text().prop_map(FilterValue::Value),
+                vec(text(), 1..4).prop_map(FilterValue::Values),
+                (option::of(text()), option::of(text()))
+                    .prop_filter("range without bounds", |(from, to)| from.is_some()
+                        || to.is_some())
+                    .prop_map(|(from, to)| FilterValue::Range { from, to }),
+                Just(FilterValue::Exists),
+            ]
+        }
+
+        fn condition() -> impl Strategy<Value = Filter> {
+            (
+                text(),
+                filter_value(),
+                option::of(operator()),
+                any::<bool>(),
+            )
+                .prop_map(|(field, value, operator, negate)| {
+                    Filter::Condition(QueryFilter {
+                        field,
+                        value,
+                        operator,
+                        negate,
+                    })
+                })
+        }
+
+        /// Filters accepted by [`QueryParams::validate`], whose groups are never empty.
+        fn filter() -> impl Strategy<Value = Filter> {
+            condition().prop_recursive(3, 12, 3, |inner| {
+                (vec(inner, 1..3), option::of(operator()), any::<bool>()).prop_map(
+                    |(conditions, operator, negate)| {
+                        Filter::Group(FilterGroup {
+                            conditions,
+                            operator,
+                            negate,
+                        })
+                    },
+                )
+            })
+        }
+
+        /// The root group, which may only be empty when its operator and negation are not set either.
+        fn root_filter() -> impl Strategy<Value = FilterGroup> {
+            (vec(filter(), 0..3), option::of(operator()), any::<bool>()).prop_map(
+                |(conditions, operator, negate)| {
+                    let empty = conditions.is_empty();
+                    FilterGroup {
+                        conditions,
+                        operator: operator.filter(|_| !empty),
+                        negate: negate && !empty,
+                    }
+                },
+            )
+        }
+
+        fn query() -> impl Strategy<Value = QueryQuery> {
+            (text(), vec(text(), 0..3), option::of(operator())).prop_map(
+                |(value, fields, operator)| QueryQuery {
+                    value,
+                    fields,
+                    operator,
+                },
+            )
+        }
+
+        fn facet() -> impl Strategy<Value = QueryFacet> {
+            let sort = (
+                prop_oneof![Just(FacetSortBy::Count), Just(FacetSortBy::Value)],
+                direction(),
+            );
+            let interval = prop_oneof![
+                Just(FacetInterval::Year),
+                Just(FacetInterval::Month),
+                Just(FacetInterval::Week),
+                Just(FacetInterval::Day),
+            ];
+            let scope = prop_oneof![Just(FacetScope::Query), Just(FacetScope::Global)];
+            (
+                text(),
+                option::of(text()),
+                option::of(any::<u32>()),
+                option::of(sort),
+                option::of(interval),
+                option::of(filter()),
+                option::of(scope),
+            )
+                .prop_map(|(field, name, limit, sort, interval, filter, scope)| {
+                    QueryFacet {
+                        field,
+                        name,
+                        limit,
+                        sort,
+                        interval,
+                        filter,
+                        scope,
+                    }
+                })
+        }
+
+        fn params() -> impl Strategy<Value = QueryParams> {
+            let profile = prop_oneof![
+                Just(QueryProfile::Minimal),
+                Just(QueryProfile::Full),
+                Just(QueryProfile::List),
+            ];
+            let preset = prop_oneof![
+                Just(QueryPreset::Minimal),
+                Just(QueryPreset::Latest),
+                Just(QueryPreset::Analysis),
+            ];
+            let sort = (text(), direction())
+                .prop_map(|(field, direction)| SortDescriptor::new(field, direction));
+            (
+                (
+                    option::of(query()),
+                    root_filter(),
+                    option::of(any::<bool>()),
+                    option::of(any::<u32>()),
+                    option::of(any::<u32>()),
+                ),
+                (
+                    vec(sort, 0..3),
+                    option::of(profile),
+                    option::of(preset),
+                    vec(text(), 0..3),
+                    vec(text(), 0..3),
+                    vec(facet(), 0..3),
+                ),
+            )
+                .prop_map(
+                    |(
+                        (query, filter, verbose, limit, offset),
+                        (sort, profile, preset, include, exclude, facets),
+                    )| QueryParams {
+                        query,
+                        filter,
+                        verbose,
+                        limit,
+                        offset,
+                        sort,
+                        profile,
+                        preset,
+                        include,
+                        exclude,
+                        facets,
+                    },
+                )
+        }
+
+        proptest! {
+            #[test]
+            fn parse_inverts_apply_to_url(params in params()) {
+                let mut url = Url::parse("https://api.reliefweb.int/v2/reports?appname=test").unwrap();
+                params.apply_to_url(&mut url);
+                prop_assert_eq!(QueryParams::from_url(url.as_str()).unwrap(), params);
+            }
+
+            #[test]
+            fn deserialize_inverts_serialize(params in params()) {
+                let json = serde_json::to_string(&params).unwrap();
+                prop_assert_eq!(serde_json::from_str::<QueryParams>(&json).unwrap(), params.clone());
+                let toml = toml::to_string(&params).unwrap();
+                prop_assert_eq!(toml::from_str::<QueryParams>(&toml).unwrap(), params);
+            }
+        }
+    }
 }
@@ -0,0 +1,225 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::time::Instant;
+
+use crate::error::{Error, Result};
+
+/// Length of the window over which the daily quota is counted.
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A client-side rate limiter keeping requests within a per-second rate and, unless created with
+/// [`RateLimiter::per_second_only`], a daily quota.
+///
+/// Requests over the per-second rate are delayed until a slot frees up, and requests over the
+/// daily quota are delayed until the daily window resets, so jobs slow down instead of failing.
+/// The daily window starts with the first request and resets 24 hours later. Use
+/// [`RateLimiter::fail_on_quota`] to fail with [`Error::QuotaExceeded`] instead of waiting for the reset.
+///
+/// Cloning a `RateLimiter` shares its budget, so one limiter can be given to several clients or tasks
+/// that identify themselves with the same `app_name`.
+///
+/// ReliefWeb currently allows 1000 calls per day for each application.
+///
+/// # Example
+///
+/// ```
+/// use reliefweb::{Client, RateLimiter, RELIEFWEB_DOMAIN};
+///
+/// let limiter = RateLimiter::new(2, 1000);
+/// let client = Client::builder(RELIEFWEB_DOMAIN, "my_app")
+///     .rate_limiter(limiter.clone())
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(limiter.remaining().daily, Some(1000));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    per_second: u32,
+    per_day: Option<u32>,
+    fail_on_quota: bool,
+    state: Arc<Mutex<BucketState>>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+    day_start: Option<Instant>,
+    day_count: u32,
+}
+
+/// A snapshot of the budget left in a [`RateLimiter`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateLimitBudget {
+    /// Number of requests that can be sent right away without being delayed.
+    pub burst: u32,
+    /// Number of requests left in the current daily window, or `None` without a daily quota.
+    pub daily: Option<u32>,
+    /// Time until the daily window resets, or `None` if no request was made yet or without a daily quota.
+    pub daily_resets_in: Option<Duration>,
+}
+
+impl RateLimiter {
+    /// Create a rate limiter allowing `per_second` requests per second and `per_day` requests per day.
+    ///
+    /// Up to `per_second` requests can be sent in a burst. Both limits are raised to at least 1.
+    pub fn new(per_second: u32, per_day: u32) -> Self {
+        Self::with_limits(per_second, Some(per_day.max(1)))
+    }
+
+    /// Create a rate limiter allowing `per_second` requests per second, without a daily quota.
+    ///
+    /// Up to `per_second` requests can be sent in a burst. The limit is raised to at least 1.
+    pub fn per_second_only(per_second: u32) -> Self {
+        Self::with_limits(per_second, None)
+    }
+
+    fn with_limits(per_second: u32, per_day: Option<u32>) -> Self {
+        let per_second = per_second.max(1);
+        Self {
+            per_second,
+            per_day,
+            fail_on_quota: false,
+            state: Arc::new(Mutex::new(BucketState {
+                tokens: per_second as f64,
+                last_refill: Instant::now(),
+                day_start: None,
+                day_count: 0,
+            })),
+        }
+    }
+
+    /// Fail requests over the daily quota with [`Error::QuotaExceeded`] instead of delaying them until the quota resets.
+    pub fn fail_on_quota(mut self, fail: bool) -> Self {
+        self.fail_on_quota = fail;
+        self
+    }
+
+    /// Returns the budget currently left.
+    pub fn remaining(&self) -> RateLimitBudget {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        self.refill(&mut state, now);
+        RateLimitBudget {
+            burst: state.tokens as u32,
+            daily: self.per_day.map(|per_day| per_day - state.day_count),
+            daily_resets_in: state
+                .day_start
+                .filter(|_| self.per_day.is_some())
+                .map(|start| DAY.saturating_sub(now - start)),
+        }
+    }
+
+    /// Waits until a request may be sent and records it against the budget.
+    ///
+    /// Fails with [`Error::QuotaExceeded`] if the daily quota is exhausted and the limiter was set to [`RateLimiter::fail_on_quota`].
+    pub(crate) async fn acquire(&self) -> Result<()> {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let now = Instant::now();
+                self.refill(&mut state, now);
+                if self
+                    .per_day
+                    .is_some_and(|per_day| state.day_count >= per_day)
+                {
+                    let start = state.day_start.unwrap_or(now);
+                    let resets_in = DAY.saturating_sub(now - start);
+                    if self.fail_on_quota {
+                        return Err(Error::QuotaExceeded { resets_in });
+                    }
+                    resets_in
+                } else if state.tokens >= 1.0 {
+                    state.tokens -= 1.0;
+                    state.day_count += 1;
+                    state.day_start.get_or_insert(now);
+                    return Ok(());
+                } else {
+                    Duration::from_secs_f64((1.0 - state.tokens) / self.per_second as f64)
+                }
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Adds the tokens earned since the last refill, and resets the daily window once elapsed.
+    fn refill(&self, state: &mut BucketState, now: Instant) {
+        let elapsed = (now - state.last_refill).as_secs_f64();
+        state.tokens =
+            (state.tokens + elapsed * self.per_second as f64).min(self.per_second as f64);
+        state.last_refill = now;
+        if state.day_start.is_some_and(|start| now - start >= DAY) {
+            state.day_start = None;
+            state.day_count = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn delays_requests_over_rate() {
+        let limiter = RateLimiter::new(2, 100);
+        let start = Instant::now();
+
+        limiter.acquire().await.unwrap();
+        limiter.acquire().await.unwrap();
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        assert_eq!(limiter.remaining().burst, 0);
+
+        limiter.acquire().await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(500));
+        assert_eq!(limiter.remaining().daily, Some(97));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn per_second_only_has_no_daily_quota() {
+        let limiter = RateLimiter::per_second_only(1000);
+        let start = Instant::now();
+
+        for _ in 0..2000 {
+            limiter.acquire().await.unwrap();
+        }
+        assert!(start.elapsed() < DAY);
+        assert_eq!(limiter.remaining().daily, None);
+        assert_eq!(limiter.remaining().daily_resets_in, None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delays_requests_over_daily_quota() {
+        let limiter = RateLimiter::new(10, 2);
+        let shared = limiter.clone();
+        let start = Instant::now();
+
+        limiter.acquire().await.unwrap();
+        shared.acquire().await.unwrap();
+        assert_eq!(shared.remaining().daily, Some(0));
+
+        limiter.acquire().await.unwrap();
+        assert!(start.elapsed() >= DAY);
+        assert_eq!(shared.remaining().daily, Some(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rejects_requests_over_daily_quota_when_failing() {
+        let limiter = RateLimiter::new(10, 2).fail_on_quota(true);
+        let shared = limiter.clone();
+
+        limiter.acquire().await.unwrap();
+        shared.acquire().await.unwrap();
+        let err = limiter.acquire().await.unwrap_err();
+        assert!(matches!(err, Error::QuotaExceeded { resets_in } if resets_in <= DAY));
+        assert_eq!(shared.remaining().daily, Some(0));
+
+        tokio::time::advance(DAY).await;
+        assert_eq!(limiter.remaining().daily, Some(2));
+        assert_eq!(limiter.remaining().daily_resets_in, None);
+        limiter.acquire().await.unwrap();
+    }
+}
@@ -0,0 +1,346 @@
+use futures::Stream;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    blog::BlogField,
+    book::BookField,
+    country::CountryField,
+    disaster::DisasterField,
+    endpoint::ResourceEndpoint,
+    error::Result,
+    job::JobField,
+    pagination::PaginationOptions,
+    params::{
+        Filter, FilterOperator, FilterValue, QueryFacet, QueryParams, QueryPreset, QueryProfile,
+        QueryQuery, SortDescriptor, SortDirection,
+    },
+    reference::ReferenceField,
+    report::ReportField,
+    response::ApiResponse,
+    source::SourceField,
+    training::TrainingField,
+};
+
+/// The field names of a resource, such as [`ReportField`](crate::report::ReportField).
+///
+/// Field names are usable wherever the API expects one: in includes and excludes, sorts, filters, full-text queries
+/// and facets. Nested fields are joined with dots, e.g. `file.preview.url-thumb`.
+pub trait ResourceField: Copy + Into<String> {
+    /// Returns the field name as used by the API.
+    fn as_str(&self) -> &'static str;
+
+    /// Returns the field with the given API name, if it exists for the resource.
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+/// A resource of the API, identified by the type of its `fields`.
+pub trait Resource: DeserializeOwned {
+    /// The field names of the resource.
+    type Field: ResourceField;
+}
+
+/// A resource whose archived or expired items are only returned with the [`QueryPreset::Analysis`] preset,
+/// i.e. disasters, jobs and trainings.
+pub trait Archivable: Resource {}
+
+/// Returns whether `field` exists for the resource at the given path, or `None` if the resource is unknown.
+///
+/// A boost such as `title^5` in full-text query fields, and the `.exact` suffix of filters and facets are ignored.
+pub(crate) fn is_known_field(resource: &str, field: &str) -> Option<bool> {
+    let field = field.split_once('^').map_or(field, |(field, _)| field);
+    let field = field.strip_suffix(".exact").unwrap_or(field);
+    let known = match resource {
+        "reports" => ReportField::from_name(field).is_some(),
+        "disasters" => DisasterField::from_name(field).is_some(),
+        "countries" => CountryField::from_name(field).is_some(),
+        "sources" => SourceField::from_name(field).is_some(),
+        "jobs" => JobField::from_name(field).is_some(),
+        "training" => TrainingField::from_name(field).is_some(),
+        "blog" => BlogField::from_name(field).is_some(),
+        "book" => BookField::from_name(field).is_some(),
+        r if r.starts_with("references/") => ReferenceField::from_name(field).is_some(),
+        _ => return None,
+    };
+    Some(known)
+}
+
+/// A query builder scoped to a resource, returned by [`ResourceEndpoint::query`].
+///
+/// Field names are the resource's own field enum, so sorting jobs by a report-only field does not compile.
+/// The query is encoded like any other [`QueryParams`].
+///
+/// # Example
+///
+/// ```no_run
+/// use reliefweb::{Client, APIVersion, SortDirection, job::JobField};
+///
+/// # async fn run() {
+/// let client = Client::new("api.reliefweb.int", "my_app", APIVersion::V2).unwrap();
+/// let jobs = client.jobs()
+///     .query()
+///     .filter(JobField::CountryIso3, "sdn")
+///     .sort(JobField::DateClosing, SortDirection::Asc)
+///     .include([JobField::Title, JobField::SourceShortname])
+///     .limit(20)
+///     .send()
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub struct ResourceQuery<'e, 'c, T> {
+    endpoint: &'e ResourceEndpoint<'c, T>,
+    params: QueryParams,
+}
+
+impl<'c, T> ResourceEndpoint<'c, T>
+where
+    T: Resource,
+{
+    /// Start building a query restricted to the fields of this resource.
+    pub fn query(&self) -> ResourceQuery<'_, 'c, T> {
+        ResourceQuery {
+            endpoint: self,
+            params: QueryParams::new(),
+        }
+    }
+}
+
+impl<'e, 'c, T> ResourceQuery<'e, 'c, T>
+where
+    T: Resource,
+{
+    /// Search for `value` in the given fields, or in all fields if `fields` is empty.
+    pub fn search(
+        mut self,
+        value: impl Into<String>,
+        fields: impl IntoIterator<Item = T::Field>,
+    ) -> Self {
+        let operator = self.params.query.take().and_then(|query| query.operator);
+        self.params.query = Some(QueryQuery {
+            operator,
+            ..QueryQuery::new(value).fields(fields)
+        });
+        self
+    }
+
+    /// Set how to interpret spaces in the search. Can be AND or OR.
+    pub fn search_operator(mut self, operator: FilterOperator) -> Self {
+        let query = self.params.query.take().unwrap_or_default();
+        self.params.query = Some(query.operator(operator));
+        self
+    }
+
+    /// Keep items whose `field` has the given value, or matches the given range.
+    pub fn filter(mut self, field: T::Field, value: impl Into<FilterValue>) -> Self {
+        self.params = self.params.filter(Filter::condition(field, value));
+        self
+    }
+
+    /// Keep items whose `field` matches at least one of the given values.
+    pub fn any_of(mut self, field: T::Field, values: impl Into<FilterValue>) -> Self {
+        self.params = self.params.filter(Filter::any_of(field, values));
+        self
+    }
+
+    /// Keep items whose `field` matches all of the given values.
+    pub fn all_of(mut self, field: T::Field, values: impl Into<FilterValue>) -> Self {
+        self.params = self.params.filter(Filter::all_of(field, values));
+        self
+    }
+
+    /// Keep items that have a value for `field`.
+    pub fn exists(mut self, field: T::Field) -> Self {
+        self.params = self.params.filter(Filter::exists(field));
+        self
+    }
+
+    /// Drop items whose `field` has the given value, or matches the given range.
+    pub fn exclude_where(mut self, field: T::Field, value: impl Into<FilterValue>) -> Self {
+        self.params = self.params.filter(!Filter::condition(field, value));
+        self
+    }
+
+    /// Add an arbitrary filter, such as a nested group.
+    ///
+    /// Field names of `filter` are not checked against the resource.
+    pub fn filter_raw(mut self, filter: impl Into<Filter>) -> Self {
+        self.params = self.params.filter(filter);
+        self
+    }
+
+    /// Set how the filters are combined. Can be AND or OR.
+    pub fn filter_operator(mut self, operator: FilterOperator) -> Self {
+        self.params = self.params.filter_operator(operator);
+        self
+    }
+
+    /// Sort results by `field`. Calling it several times sorts by each field in turn.
+    pub fn sort(mut self, field: T::Field, direction: SortDirection) -> Self {
+        self.params = self
+            .params
+            .sort(vec![SortDescriptor::new(field, direction)]);
+        self
+    }
+
+    pub fn include(mut self, fields: impl IntoIterator<Item = T::Field>) -> Self {
+        self.params = self.params.include_fields(fields);
+        self
+    }
+
+    pub fn exclude(mut self, fields: impl IntoIterator<Item = T::Field>) -> Self {
+        self.params = self.params.exclude_fields(fields);
+        self
+    }
+
+    /// Aggregate matching items by the values of `field`.
+    pub fn facet(self, field: T::Field) -> Self {
+        self.facet_with(field, |facet| facet)
+    }
+
+    /// Aggregate matching items by the values of `field`, configuring the facet with `configure`.
+    pub fn facet_with(
+        mut self,
+        field: T::Field,
+        configure: impl FnOnce(QueryFacet) -> QueryFacet,
+    ) -> Self {
+        self.params = self.params.facet(configure(QueryFacet::new(field)));
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params = self.params.limit(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.params = self.params.offset(offset);
+        self
+    }
+
+    pub fn profile(mut self, profile: QueryProfile) -> Self {
+        self.params = self.params.profile(profile);
+        self
+    }
+
+    /// Use the `latest` preset, sorting results by date (or by id for countries and sources).
+    pub fn latest(mut self) -> Self {
+        self.params = self.params.preset(QueryPreset::Latest);
+        self
+    }
+
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.params = self.params.verbose(verbose);
+        self
+    }
+
+    /// Returns the parameters built so far.
+    pub fn params(&self) -> &QueryParams {
+        &self.params
+    }
+
+    /// Returns the built parameters, e.g. to reuse them with [`ResourceEndpoint::list_post`].
+    pub fn into_params(self) -> QueryParams {
+        self.params
+    }
+
+    /// Send the query as a `list` request.
+    pub async fn send(&self) -> Result<ApiResponse<T>> {
+        self.endpoint.list(Some(&self.params)).await
+    }
+
+    /// Stream every page of the results, like [`ResourceEndpoint::pages`].
+    pub fn pages(self, options: PaginationOptions) -> impl Stream<Item = Result<ApiResponse<T>>> {
+        self.endpoint.pages(self.params, options)
+    }
+}
+
+impl<'e, 'c, T> ResourceQuery<'e, 'c, T>
+where
+    T: Archivable,
+{
+    /// Use the `analysis` preset, also returning archived disasters and expired jobs and trainings.
+    pub fn analysis(mut self) -> Self {
+        self.params = self.params.preset(QueryPreset::Analysis);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use crate::{Client, FilterValue, SortDirection, disaster::DisasterField, report::ReportField};
+
+    fn client(server: &MockServer) -> Client {
+        Client::new_with_scheme(
+            "http",
+            format!("{}:{}", server.host(), server.port()).as_str(),
+            "testapp",
+            crate::APIVersion::V2,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn builds_params() {
+        let client =
+            Client::new_with_scheme("http", "localhost:1", "testapp", crate::APIVersion::V2)
+                .unwrap();
+        let reports = client.reports();
+
+        let query = reports
+            .query()
+            .search("cholera", [ReportField::Title])
+            .filter(ReportField::PrimaryCountryIso3, "hti")
+            .exclude_where(ReportField::FormatName, "Map")
+            .sort(ReportField::DateCreated, SortDirection::Desc)
+            .include([ReportField::Title, ReportField::Url])
+            .facet_with(ReportField::SourceShortname, |f| f.limit(5))
+            .limit(10);
+
+        let params = query.params();
+        let search = params.query.as_ref().unwrap();
+        assert_eq!(search.value, "cholera");
+        assert_eq!(search.fields, ["title"]);
+        assert_eq!(params.filter.len(), 2);
+        assert_eq!(params.sort[0].field, "date.created");
+        assert_eq!(params.include, ["title", "url"]);
+        assert_eq!(params.facets[0].field, "source.shortname");
+        assert_eq!(params.facets[0].limit, Some(5));
+        assert_eq!(params.limit, Some(10));
+    }
+
+    #[tokio::test]
+    async fn sends_query() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v2/disasters")
+                .query_param("preset", "analysis")
+                .query_param("filter[conditions][0][field]", "date.event")
+                .query_param(
+                    "filter[conditions][0][value][from]",
+                    "2020-01-01T00:00:00+00:00",
+                );
+            then.status(200).json_body_obj(&serde_json::json!({
+                "data": [{ "id": "1", "fields": { "name": "Disaster 1" } }]
+            }));
+        });
+
+        let client = client(&server);
+        let resp = client
+            .disasters()
+            .query()
+            .filter(
+                DisasterField::DateEvent,
+                FilterValue::at_least("2020-01-01T00:00:00+00:00"),
+            )
+            .analysis()
+            .send()
+            .await
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(resp.data[0].fields.name.as_deref(), Some("Disaster 1"));
+    }
+}
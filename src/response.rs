@@ -1,4 +1,6 @@
-use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Represents a paginated API response from the ReliefWeb API.
 ///
@@ -7,7 +9,7 @@ use serde::{Deserialize, Serialize};
 /// # Example
 ///
 /// ```no_run
-/// use reliefweb::response::{ApiResponse, ApiItem};
+/// use reliefweb::{ApiResponse, ApiItem};
 /// use serde_json::Value;
 ///
 /// let json = r#"
@@ -38,6 +40,252 @@ pub struct ApiResponse<T> {
     pub count: Option<u32>,
     /// The list of items returned by the API.
     pub data: Vec<ApiItem<T>>,
+    /// Additional data computed for the request, such as facets.
+    pub embedded: Option<Embedded>,
+    /// How the API interpreted the request parameters, returned when [`QueryParams::verbose`](crate::QueryParams::verbose) is set.
+    pub details: Option<RequestDetails>,
+}
+
+/// Represents the request parameters as interpreted by the API, returned in verbose mode.
+///
+/// # Example
+///
+/// ```
+/// use reliefweb::ApiResponse;
+/// use serde_json::Value;
+///
+/// let json = r#"
+/// {
+///     "data": [],
+///     "details": {
+///         "limit": 5,
+///         "sort": ["date.created:desc"],
+///         "filter": { "field": "country.iso3", "value": ["syr"] }
+///     }
+/// }
+/// "#;
+///
+/// let resp: ApiResponse<Value> = serde_json::from_str(json).unwrap();
+/// let details = resp.details.unwrap();
+/// assert_eq!(details.limit, Some(5));
+/// assert_eq!(details.filter.unwrap().field.as_deref(), Some("country.iso3"));
+/// ```
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RequestDetails {
+    /// The application name of the request.
+    pub appname: Option<String>,
+    /// Number of results requested.
+    pub limit: Option<u32>,
+    /// Number of results skipped.
+    pub offset: Option<u32>,
+    /// The profile applied to the request.
+    pub profile: Option<String>,
+    /// The preset applied to the request.
+    pub preset: Option<String>,
+    /// The full-text query.
+    pub query: Option<DetailsQuery>,
+    /// The filter tree.
+    pub filter: Option<DetailsFilter>,
+    /// Sort criteria, in the form `field:direction`.
+    #[serde(default)]
+    pub sort: Vec<String>,
+    /// Fields included in or excluded from the results.
+    pub fields: Option<DetailsFields>,
+    /// The requested facets.
+    #[serde(default)]
+    pub facets: Vec<DetailsFacet>,
+    /// Any other parameter echoed by the API.
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+/// Represents a full-text query as interpreted by the API.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DetailsQuery {
+    /// What is searched for.
+    pub value: Option<String>,
+    /// Which fields are searched.
+    #[serde(default)]
+    pub fields: Vec<String>,
+    /// How spaces in the query are interpreted.
+    pub operator: Option<String>,
+}
+
+/// Represents a node of a filter tree as interpreted by the API: a condition when `field` is set, a group otherwise.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DetailsFilter {
+    /// The field of a condition.
+    pub field: Option<String>,
+    /// The value of a condition, absent for existence checks.
+    pub value: Option<DetailsFilterValue>,
+    /// How values or nested conditions are combined.
+    pub operator: Option<String>,
+    /// Whether the filter is negated.
+    pub negate: Option<bool>,
+    /// The nested filters of a group.
+    #[serde(default)]
+    pub conditions: Vec<DetailsFilter>,
+}
+
+/// Represents the value of a filter condition as interpreted by the API.
+///
+/// An object is only read as a [`DetailsFilterValue::Range`] when its only keys are `from` and `to`, with at least one
+/// of them; any other value is kept as a [`DetailsFilterValue::Value`].
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged, from = "serde_json::Value")]
+pub enum DetailsFilterValue {
+    /// A list of values.
+    Values(Vec<serde_json::Value>),
+    /// A date or numeric range.
+    Range {
+        from: Option<serde_json::Value>,
+        to: Option<serde_json::Value>,
+    },
+    /// A single value.
+    Value(serde_json::Value),
+}
+
+impl From<serde_json::Value> for DetailsFilterValue {
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Array(values) => DetailsFilterValue::Values(values),
+            serde_json::Value::Object(mut range)
+                if !range.is_empty() && range.keys().all(|key| key == "from" || key == "to") =>
+            {
+                let mut bound = |key| range.remove(key).filter(|bound| !bound.is_null());
+                DetailsFilterValue::Range {
+                    from: bound("from"),
+                    to: bound("to"),
+                }
+            }
+            value => DetailsFilterValue::Value(value),
+        }
+    }
+}
+
+/// Represents the fields included in or excluded from the results, as interpreted by the API.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DetailsFields {
+    /// Fields included in the results.
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Fields excluded from the results.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// Represents a facet request as interpreted by the API.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DetailsFacet {
+    /// The field aggregated on.
+    pub field: Option<String>,
+    /// The name of the facet in the response.
+    pub name: Option<String>,
+    /// Maximum number of buckets.
+    pub limit: Option<u32>,
+    /// How buckets are sorted, in the form `count:desc` or `value:asc`.
+    pub sort: Option<String>,
+    /// Bucket interval, for date fields.
+    pub interval: Option<String>,
+    /// The filter restricting the facet.
+    pub filter: Option<DetailsFilter>,
+    /// Whether the facet is computed on the request results or on all items.
+    pub scope: Option<String>,
+}
+
+/// Represents additional data embedded in an API response.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Embedded {
+    /// Facets requested with [`QueryParams::facet`](crate::QueryParams::facet), by name.
+    #[serde(default)]
+    pub facets: HashMap<String, Facet>,
+}
+
+/// Represents the buckets of a facet.
+///
+/// # Example
+///
+/// ```
+/// use reliefweb::{ApiResponse, FacetType};
+/// use serde_json::Value;
+///
+/// let json = r#"
+/// {
+///     "data": [],
+///     "embedded": {
+///         "facets": {
+///             "country": {
+///                 "type": "term",
+///                 "data": [{ "value": "Syria", "count": 120 }, { "value": "Iraq", "count": 80 }],
+///                 "more": true
+///             }
+///         }
+///     }
+/// }
+/// "#;
+///
+/// let resp: ApiResponse<Value> = serde_json::from_str(json).unwrap();
+/// let countries = resp.facet("country").unwrap();
+/// assert_eq!(countries.facet_type, FacetType::Term);
+/// assert_eq!(countries.data[0].value, "Syria");
+/// ```
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Facet {
+    /// The kind of aggregation.
+    #[serde(rename = "type", default)]
+    pub facet_type: FacetType,
+    /// The buckets of the facet.
+    #[serde(default)]
+    pub data: Vec<FacetBucket>,
+    /// Whether more buckets exist than were returned.
+    pub more: Option<bool>,
+    /// Number of matching items without a value for the field.
+    pub missing: Option<u64>,
+}
+
+/// The kind of aggregation of a [`Facet`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FacetType {
+    /// Buckets of distinct field values.
+    Term,
+    /// Buckets of date intervals, as requested with [`QueryFacet::interval`](crate::QueryFacet::interval).
+    Date,
+    /// A kind of aggregation this client does not know about.
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Represents a single bucket of a facet.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FacetBucket {
+    /// The field value of the bucket, or the start date of the interval for date facets.
+    #[serde(deserialize_with = "string_or_number")]
+    pub value: String,
+    /// Number of items in the bucket.
+    pub count: u64,
+}
+
+impl<T> ApiResponse<T> {
+    /// Returns the facet with the given name, if it was returned by the API.
+    pub fn facet(&self, name: &str) -> Option<&Facet> {
+        self.embedded.as_ref()?.facets.get(name)
+    }
+}
+
+/// Deserializes a value that the API returns either as a string or as a number.
+fn string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => Ok(s),
+        serde_json::Value::Number(n) => Ok(n.to_string()),
+        other => Err(serde::de::Error::custom(format!(
+            "expected a string or a number, got {other}"
+        ))),
+    }
 }
 
 /// Represents pagination and related links for an API response.
@@ -73,3 +321,183 @@ pub struct ApiItem<T> {
     /// Optional URL to this item’s API resource.
     pub href: Option<String>,
 }
+
+/// Represents an error payload returned by the ReliefWeb API alongside a non-success HTTP status.
+///
+/// # Example
+///
+/// ```
+/// use reliefweb::ApiErrorResponse;
+///
+/// let json = r#"
+/// {
+///     "time": 3,
+///     "status": 404,
+///     "error": { "type": "NotFoundHttpException", "message": "Item not found" }
+/// }
+/// "#;
+///
+/// let err: ApiErrorResponse = serde_json::from_str(json).unwrap();
+/// assert_eq!(err.message(), Some("Item not found"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiErrorResponse {
+    /// Timestamp of the API response.
+    pub time: Option<u32>,
+    /// The HTTP status code reported by the API.
+    pub status: Option<u16>,
+    /// Details about the error.
+    pub error: Option<ApiErrorDetails>,
+}
+
+/// Describes the cause of an API error.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiErrorDetails {
+    /// The kind of error raised by the API.
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    /// Human-readable description of the error.
+    pub message: Option<String>,
+}
+
+impl ApiErrorResponse {
+    /// Returns the human-readable error message, if the API provided one.
+    pub fn message(&self) -> Option<&str> {
+        self.error.as_ref()?.message.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_facets() {
+        let json = serde_json::json!({
+            "data": [],
+            "embedded": {
+                "facets": {
+                    "theme": {
+                        "type": "term",
+                        "data": [{ "value": "Health", "count": 12 }, { "value": 42, "count": 3 }],
+                        "more": false,
+                        "missing": 4
+                    },
+                    "per_month": {
+                        "type": "date",
+                        "data": [
+                            { "value": "2024-01-01T00:00:00+00:00", "count": 30 },
+                            { "value": "2024-02-01T00:00:00+00:00", "count": 25 }
+                        ]
+                    }
+                }
+            }
+        });
+
+        let resp: ApiResponse<serde_json::Value> = serde_json::from_value(json).unwrap();
+
+        let theme = resp.facet("theme").unwrap();
+        assert_eq!(theme.facet_type, FacetType::Term);
+        assert_eq!(theme.data[1].value, "42");
+        assert_eq!(theme.missing, Some(4));
+        let per_month = resp.facet("per_month").unwrap();
+        assert_eq!(per_month.facet_type, FacetType::Date);
+        assert_eq!(per_month.data[1].count, 25);
+        assert!(resp.facet("country").is_none());
+    }
+
+    #[test]
+    fn test_details() {
+        let json = serde_json::json!({
+            "data": [],
+            "details": {
+                "appname": "testapp",
+                "limit": 10,
+                "offset": 20,
+                "preset": "latest",
+                "query": { "value": "cholera", "fields": ["title"], "operator": "AND" },
+                "filter": {
+                    "operator": "AND",
+                    "conditions": [
+                        {
+                            "operator": "OR",
+                            "conditions": [
+                                { "field": "country", "value": ["Syria", "Lebanon"] },
+                                { "field": "country", "value": "Iraq" }
+                            ]
+                        },
+                        { "field": "date.created", "value": { "from": "2024-01-01T00:00:00+00:00" } },
+                        { "field": "format", "value": ["Map"], "negate": true },
+                        { "field": "image" }
+                    ]
+                },
+                "sort": ["date.created:desc"],
+                "fields": { "include": ["title"] },
+                "facets": [{ "field": "country", "limit": 5, "sort": "count:desc" }],
+                "timezone": "UTC"
+            }
+        });
+
+        let resp: ApiResponse<serde_json::Value> = serde_json::from_value(json).unwrap();
+        let details = resp.details.unwrap();
+
+        assert_eq!(details.appname.as_deref(), Some("testapp"));
+        assert_eq!(details.offset, Some(20));
+        assert_eq!(details.query.unwrap().fields, vec!["title"]);
+        let filter = details.filter.unwrap();
+        assert_eq!(filter.operator.as_deref(), Some("AND"));
+        assert_eq!(filter.conditions[0].conditions.len(), 2);
+        assert_eq!(
+            filter.conditions[0].conditions[0].value,
+            Some(DetailsFilterValue::Values(vec![
+                serde_json::json!("Syria"),
+                serde_json::json!("Lebanon")
+            ]))
+        );
+        assert_eq!(
+            filter.conditions[0].conditions[1].value,
+            Some(DetailsFilterValue::Value(serde_json::json!("Iraq")))
+        );
+        assert_eq!(
+            filter.conditions[1].value,
+            Some(DetailsFilterValue::Range {
+                from: Some(serde_json::json!("2024-01-01T00:00:00+00:00")),
+                to: None
+            })
+        );
+        assert_eq!(filter.conditions[2].negate, Some(true));
+        assert_eq!(filter.conditions[3].value, None);
+        assert_eq!(details.sort, vec!["date.created:desc"]);
+        assert_eq!(details.fields.unwrap().include, vec!["title"]);
+        assert_eq!(details.facets[0].sort.as_deref(), Some("count:desc"));
+        assert_eq!(details.other["timezone"], "UTC");
+    }
+
+    #[test]
+    fn test_details_filter_value_shapes() {
+        let value = |json| serde_json::from_value::<DetailsFilterValue>(json).unwrap();
+        assert_eq!(
+            value(serde_json::json!({ "to": 10 })),
+            DetailsFilterValue::Range {
+                from: None,
+                to: Some(serde_json::json!(10))
+            }
+        );
+        for other in [
+            serde_json::json!({}),
+            serde_json::json!({ "form": "2020" }),
+            serde_json::json!({ "from": "2020", "gte": "2021" }),
+        ] {
+            assert_eq!(value(other.clone()), DetailsFilterValue::Value(other));
+        }
+    }
+
+    #[test]
+    fn test_no_facets() {
+        let resp: ApiResponse<serde_json::Value> =
+            serde_json::from_value(serde_json::json!({ "data": [] })).unwrap();
+        assert!(resp.embedded.is_none());
+        assert!(resp.details.is_none());
+        assert!(resp.facet("country").is_none());
+    }
+}
@@ -0,0 +1,242 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::error::Error;
+
+/// Policy deciding whether and when failed requests are retried.
+///
+/// A request is retried when it fails with a retryable HTTP status (by default 429, 500, 502, 503 and 504),
+/// or with a connection or timeout error, until `max_attempts` attempts were made.
+/// The delay between attempts grows exponentially from `initial_backoff`, capped at `max_backoff`.
+/// When the response has a `Retry-After` header, its delay is used instead, capped at `max_retry_after`.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use reliefweb::{Client, RetryPolicy, RELIEFWEB_DOMAIN};
+///
+/// let client = Client::builder(RELIEFWEB_DOMAIN, "my_app")
+///     .retry_policy(
+///         RetryPolicy::new()
+///             .max_attempts(5)
+///             .initial_backoff(Duration::from_secs(1)),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts for a single request, including the first one. Defaults to 3.
+    pub max_attempts: u32,
+    /// Delay before the first retry. Defaults to 500ms.
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between two attempts. Defaults to 30s.
+    pub max_backoff: Duration,
+    /// Factor applied to the delay after each attempt. Defaults to 2.
+    pub multiplier: f64,
+    /// Whether to randomize delays to spread out retries of concurrent requests. Defaults to `true`.
+    pub jitter: bool,
+    /// HTTP statuses that are worth retrying.
+    pub retry_statuses: Vec<StatusCode>,
+    /// Whether to retry requests failing to connect or timing out. Defaults to `true`.
+    pub retry_transport_errors: bool,
+    /// Whether to wait for the delay requested by the API in the `Retry-After` header, instead of the computed backoff. Defaults to `true`.
+    pub respect_retry_after: bool,
+    /// Upper bound for a delay requested with `Retry-After`. Defaults to 60s.
+    pub max_retry_after: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+            retry_statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_transport_errors: true,
+            respect_retry_after: true,
+            max_retry_after: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a default retry policy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a policy that never retries. This is the policy used by clients unless configured otherwise.
+    pub fn none() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn retry_statuses(mut self, statuses: Vec<StatusCode>) -> Self {
+        self.retry_statuses = statuses;
+        self
+    }
+
+    pub fn retry_transport_errors(mut self, retry: bool) -> Self {
+        self.retry_transport_errors = retry;
+        self
+    }
+
+    pub fn respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    pub fn max_retry_after(mut self, max: Duration) -> Self {
+        self.max_retry_after = max;
+        self
+    }
+
+    /// Returns whether a request failing with the given error should be attempted again.
+    pub fn is_retryable(&self, err: &Error) -> bool {
+        match err {
+            Error::Transport(e) if e.is_connect() || e.is_timeout() => self.retry_transport_errors,
+            Error::RateLimited { .. } | Error::Api { .. } | Error::Status { .. } => err
+                .status()
+                .is_some_and(|s| self.retry_statuses.contains(&s)),
+            _ => false,
+        }
+    }
+
+    /// Returns how long to wait after the given failed `attempt` (starting at 1) before trying again.
+    pub fn delay(&self, attempt: u32, err: &Error) -> Duration {
+        if self.respect_retry_after
+            && let Some(retry_after) = err.retry_after()
+        {
+            return retry_after.min(self.max_retry_after);
+        }
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let factor = self.multiplier.max(1.0).powi(exponent);
+        let backoff = Duration::from_secs_f64(
+            (self.initial_backoff.as_secs_f64() * factor).min(self.max_backoff.as_secs_f64()),
+        );
+        if self.jitter {
+            // Equal jitter: wait at least half of the backoff, plus a random share of the other half.
+            backoff / 2 + backoff.mul_f64(fastrand::f64() / 2.0)
+        } else {
+            backoff
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_grows_exponentially_and_is_capped() {
+        let policy = RetryPolicy::new()
+            .jitter(false)
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(350));
+        let err = Error::InvalidParameters(String::new());
+
+        assert_eq!(policy.delay(1, &err), Duration::from_millis(100));
+        assert_eq!(policy.delay(2, &err), Duration::from_millis(200));
+        assert_eq!(policy.delay(3, &err), Duration::from_millis(350));
+        assert_eq!(policy.delay(40, &err), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn delay_with_jitter_stays_in_bounds() {
+        let policy = RetryPolicy::new().initial_backoff(Duration::from_millis(100));
+        let err = Error::InvalidParameters(String::new());
+
+        for _ in 0..100 {
+            let delay = policy.delay(1, &err);
+            assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn delay_honors_retry_after() {
+        let err = Error::RateLimited {
+            retry_after: Some(Duration::from_secs(7)),
+            error: None,
+        };
+
+        assert_eq!(RetryPolicy::new().delay(1, &err), Duration::from_secs(7));
+        assert!(
+            RetryPolicy::new().respect_retry_after(false).delay(1, &err) < Duration::from_secs(7)
+        );
+
+        let unavailable = Error::Status {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            body: String::new(),
+            retry_after: Some(Duration::from_secs(86_400)),
+        };
+        assert_eq!(
+            RetryPolicy::new().delay(1, &unavailable),
+            Duration::from_secs(60)
+        );
+        assert_eq!(
+            RetryPolicy::new()
+                .max_retry_after(Duration::from_secs(5))
+                .delay(1, &unavailable),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn retryable_errors() {
+        let policy = RetryPolicy::new();
+        let status = |code: u16| Error::Status {
+            status: StatusCode::from_u16(code).unwrap(),
+            body: String::new(),
+            retry_after: None,
+        };
+
+        assert!(policy.is_retryable(&Error::RateLimited {
+            retry_after: None,
+            error: None,
+        }));
+        assert!(policy.is_retryable(&status(502)));
+        assert!(policy.is_retryable(&status(503)));
+        assert!(!policy.is_retryable(&status(404)));
+        assert!(!policy.is_retryable(&Error::InvalidParameters(String::new())));
+        assert!(
+            !policy
+                .retry_statuses(vec![StatusCode::BAD_GATEWAY])
+                .is_retryable(&status(503))
+        );
+    }
+}
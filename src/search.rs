@@ -0,0 +1,706 @@
+use std::{fmt, ops::Not, str::FromStr};
+
+use crate::{
+    error::{Error, Result},
+    params::{FilterOperator, QueryQuery},
+};
+
+/// Characters with a meaning in the search syntax, escaped with a backslash when part of a term.
+const SPECIAL_CHARS: &str = "+-=&|><!(){}[]^\"~*?:\\/";
+
+/// Maximum nesting of parentheses and `NOT`s when parsing, so that deeply nested input fails instead of overflowing
+/// the stack.
+const MAX_DEPTH: usize = 64;
+
+/// A full-text search expression, rendered to the search syntax of the API and used as the value of a [`QueryQuery`].
+///
+/// Terms, phrases, wildcards and field names are escaped when rendered, and clauses are always combined with explicit `AND` and
+/// `OR` operators, so the rendered string does not depend on the `operator` of the query.
+///
+/// # Example
+///
+/// ```
+/// use reliefweb::{Query, QueryQuery};
+///
+/// let query = Query::field("country", "Sudan")
+///     .and(Query::phrase("cholera outbreak"))
+///     .and(!Query::wildcard("vaccin*"));
+/// assert_eq!(query.to_string(), r#"country:Sudan AND "cholera outbreak" AND NOT vaccin*"#);
+///
+/// let parsed: Query = r#"country:(Sudan OR Chad) AND "cholera outbreak""#.parse().unwrap();
+/// assert_eq!(parsed, Query::field("country", Query::any(["Sudan", "Chad"])).and(Query::phrase("cholera outbreak")));
+///
+/// let search = QueryQuery::new(query);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Query {
+    /// A single word, matched as is. Spaces and special characters are escaped.
+    Term(String),
+    /// A sequence of words matched in order, rendered in double quotes.
+    Phrase(String),
+    /// A pattern where `*` matches any characters and `?` a single one. Other special characters are escaped.
+    Wildcard(String),
+    /// A clause restricted to the given field, e.g. `country:Sudan`.
+    Field {
+        /// The field to search in. See [field tables](https://apidoc.reliefweb.int/fields-tables).
+        field: String,
+        /// What to search for in the field.
+        value: Box<Query>,
+    },
+    /// Matches items matching all the clauses.
+    And(Vec<Query>),
+    /// Matches items matching any of the clauses.
+    Or(Vec<Query>),
+    /// Matches items not matching the clause.
+    Not(Box<Query>),
+}
+
+impl Query {
+    /// Create a clause matching the given word.
+    ///
+    /// An empty term matches nothing: it is rendered as an empty phrase, `""`, which [`Query::parse`] rejects.
+    pub fn term(value: impl Into<String>) -> Self {
+        Query::Term(value.into())
+    }
+
+    /// Create a clause matching the given words in order.
+    ///
+    /// An empty phrase matches nothing, and is rejected by [`Query::parse`].
+    pub fn phrase(value: impl Into<String>) -> Self {
+        Query::Phrase(value.into())
+    }
+
+    /// Create a clause matching words against `pattern`, where `*` matches any characters and `?` a single one.
+    pub fn wildcard(pattern: impl Into<String>) -> Self {
+        Query::Wildcard(pattern.into())
+    }
+
+    /// Restrict `value` to the given field, e.g. `Query::field(ReportField::Title, "flood")`.
+    pub fn field(field: impl Into<String>, value: impl Into<Query>) -> Self {
+        Query::Field {
+            field: field.into(),
+            value: Box::new(value.into()),
+        }
+    }
+
+    /// Create a clause matching items that match all the given clauses.
+    pub fn all(clauses: impl IntoIterator<Item = impl Into<Query>>) -> Self {
+        Query::And(clauses.into_iter().map(Into::into).collect())
+    }
+
+    /// Create a clause matching items that match any of the given clauses.
+    pub fn any(clauses: impl IntoIterator<Item = impl Into<Query>>) -> Self {
+        Query::Or(clauses.into_iter().map(Into::into).collect())
+    }
+
+    /// Combine with `other`, matching items that match both.
+    pub fn and(self, other: impl Into<Query>) -> Self {
+        match self {
+            Query::And(mut clauses) => {
+                clauses.push(other.into());
+                Query::And(clauses)
+            }
+            query => Query::And(vec![query, other.into()]),
+        }
+    }
+
+    /// Combine with `other`, matching items that match either.
+    pub fn or(self, other: impl Into<Query>) -> Self {
+        match self {
+            Query::Or(mut clauses) => {
+                clauses.push(other.into());
+                Query::Or(clauses)
+            }
+            query => Query::Or(vec![query, other.into()]),
+        }
+    }
+
+    /// Invert the clause, to match items that do not match it.
+    pub fn negate(self) -> Self {
+        match self {
+            Query::Not(query) => *query,
+            query => Query::Not(Box::new(query)),
+        }
+    }
+
+    /// Parse a search string, combining clauses separated by spaces with `operator`.
+    ///
+    /// Unescaped special characters that are not part of the syntax, e.g. the `-` of `covid-19`, are kept as part of the term.
+    /// Syntax that `Query` does not represent fails with [`Error::InvalidParameters`] rather than being searched for
+    /// literally: ranges (`[2020 TO 2024]`, `>2020`), boosts (`^`), fuzzy and proximity searches (`~`), `+`, `-` and `!`
+    /// prefixes, `&&` and `||`, and regular expressions (`/.../`). Escape these characters to search for them.
+    /// Empty phrases (`""`) are rejected too, as they match nothing.
+    pub fn parse(value: &str, operator: FilterOperator) -> Result<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(value)?,
+            pos: 0,
+            depth: 0,
+            operator,
+        };
+        let query = parser.parse_clauses()?;
+        match parser.tokens.get(parser.pos) {
+            Some((pos, _)) => Err(syntax_error(*pos, "unexpected `)`")),
+            None => Ok(query),
+        }
+    }
+
+    /// Returns `true` if the clause is a group that must be wrapped in parentheses when nested.
+    fn is_group(&self) -> bool {
+        matches!(self, Query::And(_) | Query::Or(_))
+    }
+
+    fn fmt_nested(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_group() || matches!(self, Query::Not(_) | Query::Field { .. }) {
+            write!(f, "({self})")
+        } else {
+            write!(f, "{self}")
+        }
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Query::Term(value) => write_escaped(f, value, ""),
+            Query::Wildcard(pattern) => write_escaped(f, pattern, "*?"),
+            Query::Phrase(value) => {
+                f.write_str("\"")?;
+                for c in value.chars() {
+                    if c == '"' || c == '\\' {
+                        f.write_str("\\")?;
+                    }
+                    write!(f, "{c}")?;
+                }
+                f.write_str("\"")
+            }
+            Query::Field { field, value } => {
+                write_escaped(f, field, "")?;
+                f.write_str(":")?;
+                value.fmt_nested(f)
+            }
+            Query::And(clauses) | Query::Or(clauses) => {
+                let separator = if matches!(self, Query::And(_)) {
+                    " AND "
+                } else {
+                    " OR "
+                };
+                for (i, clause) in clauses.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(separator)?;
+                    }
+                    if clause.is_group() {
+                        write!(f, "({clause})")?;
+                    } else {
+                        write!(f, "{clause}")?;
+                    }
+                }
+                Ok(())
+            }
+            Query::Not(query) => {
+                f.write_str("NOT ")?;
+                if query.is_group() {
+                    write!(f, "({query})")
+                } else {
+                    write!(f, "{query}")
+                }
+            }
+        }
+    }
+}
+
+/// Writes `value` escaping special characters and spaces, except those in `keep`.
+///
+/// A value spelling an operator gets its first character escaped, so it is not read back as one.
+fn write_escaped(f: &mut fmt::Formatter, value: &str, keep: &str) -> fmt::Result {
+    if value.is_empty() {
+        return f.write_str("\"\"");
+    }
+    let keyword = matches!(value, "AND" | "OR" | "NOT");
+    for (i, c) in value.chars().enumerate() {
+        let special = (SPECIAL_CHARS.contains(c) || c.is_whitespace()) && !keep.contains(c);
+        if special || (keyword && i == 0) {
+            f.write_str("\\")?;
+        }
+        write!(f, "{c}")?;
+    }
+    Ok(())
+}
+
+impl FromStr for Query {
+    type Err = Error;
+
+    /// Parse a search string, combining clauses separated by spaces with OR, like the API does by default.
+    fn from_str(s: &str) -> Result<Self> {
+        Query::parse(s, FilterOperator::default())
+    }
+}
+
+impl Not for Query {
+    type Output = Query;
+
+    fn not(self) -> Self::Output {
+        self.negate()
+    }
+}
+
+impl From<&str> for Query {
+    fn from(value: &str) -> Self {
+        Query::Term(value.to_string())
+    }
+}
+
+impl From<String> for Query {
+    fn from(value: String) -> Self {
+        Query::Term(value)
+    }
+}
+
+impl From<Query> for String {
+    fn from(query: Query) -> Self {
+        query.to_string()
+    }
+}
+
+impl QueryQuery {
+    /// Parse the value of the query, combining clauses separated by spaces with its `operator`.
+    pub fn parse(&self) -> Result<Query> {
+        Query::parse(&self.value, self.operator.clone().unwrap_or_default())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    Colon,
+    Phrase(String),
+    Word {
+        /// The word with escapes removed.
+        text: String,
+        /// Whether the word has unescaped `*` or `?`.
+        wildcard: bool,
+        /// Whether the word has escaped characters, so it cannot be an operator.
+        escaped: bool,
+    },
+}
+
+fn tokenize(input: &str) -> Result<Vec<(usize, Token)>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().enumerate().peekable();
+    while let Some((pos, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ':' => Token::Colon,
+            '"' => {
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, c)) => text.push(c),
+                            None => return Err(syntax_error(pos, "unterminated phrase")),
+                        },
+                        Some((_, c)) => text.push(c),
+                        None => return Err(syntax_error(pos, "unterminated phrase")),
+                    }
+                }
+                Token::Phrase(text)
+            }
+            c => {
+                let mut text = String::new();
+                let mut wildcard = false;
+                let mut escaped = false;
+                let mut next = Some((pos, c));
+                while let Some((pos, c)) = next {
+                    match c {
+                        '\\' => match chars.next() {
+                            Some((_, c)) => {
+                                text.push(c);
+                                escaped = true;
+                            }
+                            None => return Err(syntax_error(pos, "dangling escape")),
+                        },
+                        '*' | '?' => {
+                            text.push(c);
+                            wildcard = true;
+                        }
+                        c => {
+                            if let Some(syntax) = unsupported_syntax(c, text.is_empty()) {
+                                return Err(syntax_error(
+                                    pos,
+                                    &format!(
+                                        "{syntax} are not supported, escape `{c}` to search for it"
+                                    ),
+                                ));
+                            }
+                            text.push(c);
+                        }
+                    }
+                    next = chars.next_if(|&(_, c)| {
+                        !(c.is_whitespace() || matches!(c, '(' | ')' | ':' | '"'))
+                    });
+                }
+                if !escaped && matches!(text.as_str(), "&&" | "||") {
+                    return Err(syntax_error(
+                        pos,
+                        &format!("`{text}` is not supported, use `AND` or `OR`"),
+                    ));
+                }
+                Token::Word {
+                    text,
+                    wildcard,
+                    escaped,
+                }
+            }
+        };
+        tokens.push((pos, token));
+    }
+    Ok(tokens)
+}
+
+/// Returns the syntax an unescaped character of a word stands for, if the search syntax has it but `Query` does not
+/// represent it. Some characters only have a meaning at the start of a word, e.g. the `-` of `-flood` but not the one
+/// of `covid-19`.
+fn unsupported_syntax(c: char, first: bool) -> Option<&'static str> {
+    match c {
+        '[' | ']' | '{' | '}' => Some("ranges"),
+        '>' | '<' if first => Some("ranges"),
+        '^' => Some("boosts"),
+        '~' => Some("fuzzy and proximity searches"),
+        '+' | '-' | '!' if first => Some("`+`, `-` and `!` prefixes"),
+        '/' if first => Some("regular expressions"),
+        _ => None,
+    }
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    /// The current nesting of parentheses and `NOT`s.
+    depth: usize,
+    operator: FilterOperator,
+}
+
+impl Parser {
+    /// Parses clauses up to the end or a closing parenthesis, combining them with the default operator.
+    fn parse_clauses(&mut self) -> Result<Query> {
+        let mut clauses = Vec::new();
+        while !matches!(self.peek(), None | Some(Token::RParen)) {
+            clauses.push(self.parse_or()?);
+        }
+        match clauses.len() {
+            0 => Err(syntax_error(self.position(), "empty query")),
+            1 => Ok(clauses.remove(0)),
+            _ => Ok(match self.operator {
+                FilterOperator::AND => Query::And(clauses),
+                FilterOperator::OR => Query::Or(clauses),
+            }),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Query> {
+        let mut clauses = vec![self.parse_and()?];
+        while self.eat_keyword("OR") {
+            clauses.push(self.parse_and()?);
+        }
+        Ok(if clauses.len() == 1 {
+            clauses.remove(0)
+        } else {
+            Query::Or(clauses)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Query> {
+        let mut clauses = vec![self.parse_unary()?];
+        while self.eat_keyword("AND") {
+            clauses.push(self.parse_unary()?);
+        }
+        Ok(if clauses.len() == 1 {
+            clauses.remove(0)
+        } else {
+            Query::And(clauses)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<Query> {
+        let position = self.position();
+        if self.eat_keyword("NOT") {
+            let query = self.nested(position, Self::parse_unary)?;
+            Ok(Query::Not(Box::new(query)))
+        } else {
+            self.parse_primary(true)
+        }
+    }
+
+    fn parse_primary(&mut self, allow_field: bool) -> Result<Query> {
+        let position = self.position();
+        let Some((_, token)) = self.tokens.get(self.pos) else {
+            return Err(syntax_error(position, "unexpected end of query"));
+        };
+        self.pos += 1;
+        match token {
+            Token::LParen => {
+                let query = self.nested(position, Self::parse_clauses)?;
+                if self.peek() == Some(&Token::RParen) {
+                    self.pos += 1;
+                    Ok(query)
+                } else {
+                    Err(syntax_error(position, "unclosed `(`"))
+                }
+            }
+            Token::Phrase(text) if text.is_empty() => Err(syntax_error(position, "empty phrase")),
+            Token::Phrase(text) => Ok(Query::Phrase(text.clone())),
+            Token::Word {
+                text,
+                escaped: false,
+                ..
+            } if matches!(text.as_str(), "AND" | "OR" | "NOT") => {
+                Err(syntax_error(position, &format!("unexpected `{text}`")))
+            }
+            Token::Word { text, wildcard, .. } => {
+                let text = text.clone();
+                if self.peek() == Some(&Token::Colon) {
+                    if !allow_field {
+                        return Err(syntax_error(self.position(), "unexpected `:`"));
+                    }
+                    self.pos += 1;
+                    let value = self.parse_primary(false)?;
+                    Ok(Query::Field {
+                        field: text,
+                        value: Box::new(value),
+                    })
+                } else if *wildcard {
+                    Ok(Query::Wildcard(text))
+                } else {
+                    Ok(Query::Term(text))
+                }
+            }
+            Token::RParen => Err(syntax_error(position, "unexpected `)`")),
+            Token::Colon => Err(syntax_error(position, "unexpected `:`")),
+        }
+    }
+
+    /// Runs `parse` one level deeper, failing if the query is nested too deeply.
+    fn nested(&mut self, position: usize, parse: fn(&mut Self) -> Result<Query>) -> Result<Query> {
+        if self.depth == MAX_DEPTH {
+            return Err(syntax_error(position, "query nested too deeply"));
+        }
+        self.depth += 1;
+        let query = parse(self);
+        self.depth -= 1;
+        query
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, token)| token)
+    }
+
+    /// Returns the character offset of the current token, or of the end of the query.
+    fn position(&self) -> usize {
+        match self.tokens.get(self.pos) {
+            Some((pos, _)) => *pos,
+            None => self.tokens.last().map_or(0, |(pos, _)| pos + 1),
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = matches!(
+            self.peek(),
+            Some(Token::Word { text, escaped: false, .. }) if text == keyword
+        );
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+}
+
+fn syntax_error(position: usize, message: &str) -> Error {
+    Error::InvalidParameters(format!(
+        "invalid search query at character {position}: {message}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let query = Query::field("country", "Sudan")
+            .and(Query::phrase("cholera outbreak"))
+            .and(Query::term("covid-19").or(Query::wildcard("vaccin*")))
+            .and(!Query::field("format", Query::any(["Map", "Infographic"])));
+        assert_eq!(
+            query.to_string(),
+            r#"country:Sudan AND "cholera outbreak" AND (covid\-19 OR vaccin*) AND NOT format:(Map OR Infographic)"#
+        );
+
+        assert_eq!(Query::term("South Sudan").to_string(), r"South\ Sudan");
+        assert_eq!(Query::term("a*b?").to_string(), r"a\*b\?");
+        assert_eq!(Query::wildcard("a:b*").to_string(), r"a\:b*");
+        assert_eq!(
+            Query::phrase(r#"say "hi"\"#).to_string(),
+            r#""say \"hi\"\\""#
+        );
+        assert_eq!(Query::term("AND").to_string(), r"\AND");
+        assert_eq!(Query::term("").to_string(), r#""""#);
+        assert_eq!(!!Query::term("flood"), Query::term("flood"));
+        assert_eq!(
+            String::from(Query::field("title", !Query::term("flood"))),
+            "title:(NOT flood)"
+        );
+        assert_eq!(
+            Query::field("title:x OR body", "flood").to_string(),
+            r"title\:x\ OR\ body:flood"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "country:(Sudan OR Chad) AND \"cholera outbreak\""
+                .parse::<Query>()
+                .unwrap(),
+            Query::field("country", Query::any(["Sudan", "Chad"]))
+                .and(Query::phrase("cholera outbreak"))
+        );
+        assert_eq!(
+            "a OR b AND NOT c".parse::<Query>().unwrap(),
+            Query::term("a").or(Query::term("b").and(!Query::term("c")))
+        );
+        assert_eq!(
+            "flood earthquake".parse::<Query>().unwrap(),
+            Query::any(["flood", "earthquake"])
+        );
+        assert_eq!(
+            Query::parse("flood earthquake", FilterOperator::AND).unwrap(),
+            Query::all(["flood", "earthquake"])
+        );
+        assert_eq!(
+            r"covid-19 South\ Sudan vaccin* \AND"
+                .parse::<Query>()
+                .unwrap(),
+            Query::any([
+                Query::term("covid-19"),
+                Query::term("South Sudan"),
+                Query::wildcard("vaccin*"),
+                Query::term("AND"),
+            ])
+        );
+        assert_eq!(
+            QueryQuery::new("title:flood OR body:flood")
+                .operator(FilterOperator::AND)
+                .parse()
+                .unwrap(),
+            Query::field("title", "flood").or(Query::field("body", "flood"))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for input in [
+            "",
+            "  ",
+            "(flood",
+            "flood)",
+            "\"cholera",
+            "flood\\",
+            "AND flood",
+            "flood OR",
+            "NOT",
+            "a:b:c",
+            ":flood",
+            "()",
+            "\"\"",
+            "flood:\"\"",
+            "date:[2020 TO 2024]",
+            "year:{2020 TO *}",
+            "year:>2020",
+            "flood^2",
+            "\"cholera outbreak\"~3",
+            "floof~",
+            "+flood -drought",
+            "flood -drought",
+            "!flood",
+            "flood && drought",
+            "flood || drought",
+            "/flo+d/",
+        ] {
+            assert!(
+                matches!(input.parse::<Query>(), Err(Error::InvalidParameters(_))),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            r"covid-19 a\-b \+1 x\^2 \[draft\]"
+                .parse::<Query>()
+                .unwrap(),
+            Query::any(["covid-19", "a-b", "+1", "x^2", "[draft]"])
+        );
+
+        let deep = format!("{}flood{}", "(".repeat(200_000), ")".repeat(200_000));
+        assert!(matches!(
+            deep.parse::<Query>(),
+            Err(Error::InvalidParameters(_))
+        ));
+        let deep = format!("{}flood", "NOT ".repeat(200_000));
+        assert!(matches!(
+            deep.parse::<Query>(),
+            Err(Error::InvalidParameters(_))
+        ));
+        let nested = format!("{}flood{}", "(".repeat(MAX_DEPTH), ")".repeat(MAX_DEPTH));
+        assert_eq!(nested.parse::<Query>().unwrap(), Query::term("flood"));
+    }
+
+    mod round_trip {
+        use proptest::{collection::vec, prelude::*};
+
+        use super::*;
+
+        /// Queries in their canonical form: groups have at least two clauses and wildcards have a `*` or `?`.
+        /// Terms and phrases may be empty, which parsing rejects.
+        fn query() -> impl Strategy<Value = Query> {
+            let leaf = prop_oneof![
+                "[^\\x00]{0,8}".prop_map(Query::Term),
+                "[^\\x00]{0,8}".prop_map(Query::Phrase),
+                "[a-z:/ -]{0,4}[*?][a-z*?]{0,4}".prop_map(Query::Wildcard),
+            ];
+            let field = ("[^\\x00]{1,8}", leaf.clone())
+                .prop_map(|(field, value)| Query::field(field, value));
+            prop_oneof![leaf, field].prop_recursive(3, 16, 4, |inner| {
+                prop_oneof![
+                    vec(inner.clone(), 2..4).prop_map(Query::And),
+                    vec(inner.clone(), 2..4).prop_map(Query::Or),
+                    inner.clone().prop_map(|query| Query::Not(Box::new(query))),
+                    ("[^\\x00]{1,8}", inner).prop_map(|(field, value)| Query::field(field, value)),
+                ]
+            })
+        }
+
+        /// Returns `true` if the query has an empty term or phrase.
+        fn has_empty(query: &Query) -> bool {
+            match query {
+                Query::Term(value) | Query::Phrase(value) => value.is_empty(),
+                Query::Wildcard(_) => false,
+                Query::Field { value, .. } => has_empty(value),
+                Query::And(clauses) | Query::Or(clauses) => clauses.iter().any(has_empty),
+                Query::Not(query) => has_empty(query),
+            }
+        }
+
+        proptest! {
+            #[test]
+            fn parse_inverts_render(query in query()) {
+                let parsed = query.to_string().parse::<Query>();
+                if has_empty(&query) {
+                    prop_assert!(matches!(parsed, Err(Error::InvalidParameters(_))));
+                } else {
+                    prop_assert_eq!(parsed.unwrap(), query);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,569 @@
+#[cfg(test)]
+mod fields_tests {
+    use std::collections::BTreeSet;
+
+    use reliefweb::{
+        ApiResponse, Resource, ResourceField,
+        blog::BlogFields,
+        book::BookFields,
+        country::{CountryFields, CountryStatus},
+        disaster::{DisasterFields, DisasterStatus},
+        job::{JobFields, JobStatus},
+        reference::ReferenceFields,
+        report::{ReportFields, ReportStatus},
+        source::{SourceFields, SourceStatus},
+        training::{TrainingCost, TrainingFields, TrainingStatus},
+    };
+
+    /// Deserializes a fixture of `tests/fixtures`, reporting the JSON path of the offending value on failure.
+    ///
+    /// The fixtures are synthetic, see `tests/fixtures/README.md`.
+    fn fixture<T>(json: &str) -> T
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let de = &mut serde_json::Deserializer::from_str(json);
+        serde_path_to_error::deserialize(de).unwrap_or_else(|e| panic!("{}: {}", e.path(), e))
+    }
+
+    #[test]
+    fn report() {
+        let resp: ApiResponse<ReportFields> = fixture(include_str!("fixtures/report.json"));
+        let report = &resp.data[0].fields;
+        assert_eq!(report.status, Some(ReportStatus::Published));
+
+        let file = &report.file.as_ref().unwrap()[0];
+        assert_eq!(file.id, Some(5240193));
+        assert_eq!(file.mimetype.as_deref(), Some("application/pdf"));
+        assert_eq!(file.filesize, Some(584322));
+        assert!(
+            file.preview
+                .as_ref()
+                .and_then(|p| p.url_thumb.as_deref())
+                .is_some_and(|url| url.ends_with(".png"))
+        );
+
+        let image = report.image.as_ref().unwrap();
+        assert_eq!((image.width, image.height), (Some(1200), Some(800)));
+        assert_eq!(image.filesize, Some(254101));
+        assert_eq!(image.copyright.as_deref(), Some("OCHA/John Doe"));
+
+        let headline = report.headline.as_ref().unwrap();
+        assert_eq!(
+            headline.title.as_deref(),
+            Some("Flash floods hit eastern Sudan")
+        );
+        assert_eq!(headline.image.as_ref().unwrap().id, Some(3312));
+        assert_eq!(headline.image.as_ref().unwrap().width, None);
+
+        let disaster = &report.disaster.as_ref().unwrap()[0];
+        assert_eq!(disaster.glide.as_deref(), Some("FL-2024-000130-SDN"));
+        assert_eq!(
+            disaster.disaster_type.as_ref().unwrap()[0].code.as_deref(),
+            Some("FF")
+        );
+        assert_eq!(
+            report.disaster_type.as_ref().unwrap()[0].name.as_deref(),
+            Some("Flash Flood")
+        );
+        assert_eq!(report.vulnerable_groups.as_ref().unwrap()[0].id, Some(5));
+        assert_eq!(
+            report.ocha_product.as_ref().unwrap()[0].name.as_deref(),
+            Some("Flash Update")
+        );
+        assert_eq!(report.feature.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn disaster() {
+        let resp: ApiResponse<DisasterFields> = fixture(include_str!("fixtures/disaster.json"));
+        let disaster = &resp.data[0].fields;
+        assert_eq!(disaster.status, Some(DisasterStatus::Ongoing));
+
+        assert_eq!(disaster.current, Some(true));
+        assert_eq!(disaster.featured, Some(false));
+        assert_eq!(
+            disaster.date.as_ref().unwrap().event.as_deref(),
+            Some("2024-07-28T00:00:00+00:00")
+        );
+        assert_eq!(
+            disaster.related_glide.as_deref(),
+            Some(
+                &[
+                    "FL-2024-000130-SDN".to_string(),
+                    "FL-2024-000131-TCD".to_string()
+                ][..]
+            )
+        );
+
+        let profile = disaster.profile.as_ref().unwrap();
+        assert!(profile.overview_html.as_deref().unwrap().starts_with("<p>"));
+        assert_eq!(profile.key_content.as_ref().unwrap().len(), 1);
+        assert_eq!(
+            profile.appeals_response_plans.as_ref().unwrap()[0]
+                .title
+                .as_deref(),
+            Some("Sudan Humanitarian Response Plan 2024")
+        );
+        let link = &profile.useful_links.as_ref().unwrap()[0];
+        assert_eq!(link.url.as_deref(), Some("https://www.unocha.org/sudan"));
+        assert!(link.logo.is_some());
+    }
+
+    #[test]
+    fn country() {
+        let resp: ApiResponse<CountryFields> = fixture(include_str!("fixtures/country.json"));
+        let country = &resp.data[0].fields;
+        assert_eq!(country.status, Some(CountryStatus::Current));
+
+        assert_eq!(country.current, Some(true));
+        assert_eq!(country.featured, Some(true));
+        assert!(country.description.as_deref().unwrap().starts_with("Sudan"));
+        let profile = country.profile.as_ref().unwrap();
+        assert!(profile.overview.is_some());
+        assert!(profile.key_content.is_none());
+        assert_eq!(profile.useful_links.as_ref().unwrap()[0].logo, None);
+    }
+
+    #[test]
+    fn source() {
+        let resp: ApiResponse<SourceFields> = fixture(include_str!("fixtures/source.json"));
+        let source = &resp.data[0].fields;
+        assert_eq!(source.status, Some(SourceStatus::Active));
+
+        assert_eq!(source.shortname.as_deref(), Some("OCHA"));
+        assert!(
+            source
+                .longname
+                .as_deref()
+                .unwrap()
+                .starts_with("United Nations")
+        );
+        assert!(source.spanish_name.is_some());
+        assert_eq!(source.homepage.as_deref(), Some("https://www.unocha.org"));
+        assert!(source.description.is_some() && source.disclaimer.is_some());
+
+        let logo = source.logo.as_ref().unwrap();
+        assert_eq!(logo.id, Some(2201));
+        assert_eq!((logo.width, logo.height), (Some(300), Some(120)));
+        assert_eq!(logo.filesize, Some(10837));
+    }
+
+    #[test]
+    fn job() {
+        let resp: ApiResponse<JobFields> = fixture(include_str!("fixtures/job.json"));
+        let job = &resp.data[0].fields;
+
+        assert_eq!(
+            job.country.as_ref().unwrap()[0].iso3.as_deref(),
+            Some("sdn")
+        );
+        let cities: Vec<_> = job
+            .city
+            .iter()
+            .flatten()
+            .filter_map(|c| c.name.as_deref())
+            .collect();
+        assert_eq!(cities, ["Kassala", "Port Sudan"]);
+        assert!(
+            job.how_to_apply_html
+                .as_deref()
+                .unwrap()
+                .contains("<a href")
+        );
+        assert_eq!(
+            job.date.as_ref().unwrap().closing.as_deref(),
+            Some("2024-08-31T00:00:00+00:00")
+        );
+    }
+
+    #[test]
+    fn training() {
+        let resp: ApiResponse<TrainingFields> = fixture(include_str!("fixtures/training.json"));
+        let training = &resp.data[0].fields;
+
+        assert_eq!(training.status, Some(TrainingStatus::Published));
+        assert_eq!(training.cost, Some(TrainingCost::Fee));
+        assert!(!training.is_free());
+        assert!(training.fee_information.is_some());
+        assert!(training.is_online());
+        assert_eq!(
+            training.online_url(),
+            Some("https://learning.example.org/courses/negotiation")
+        );
+        assert_eq!(
+            training.city.as_ref().unwrap()[0].name.as_deref(),
+            Some("Geneva")
+        );
+        assert_eq!(training.country.as_ref().unwrap()[0].id, Some(254));
+
+        let dates = training.date.as_ref().unwrap();
+        assert_eq!(
+            dates.registration.as_deref(),
+            Some("2024-09-01T00:00:00+00:00")
+        );
+        assert!(dates.start.is_some() && dates.end.is_some());
+    }
+
+    #[test]
+    fn statuses() {
+        let statuses: Vec<DisasterStatus> =
+            serde_json::from_str(r#"["alert", "ongoing", "past", "dormant"]"#).unwrap();
+        assert_eq!(
+            statuses,
+            [
+                DisasterStatus::Alert,
+                DisasterStatus::Ongoing,
+                DisasterStatus::Past,
+                DisasterStatus::Unknown("dormant".to_string())
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&statuses).unwrap(),
+            r#"["alert","ongoing","past","dormant"]"#
+        );
+        assert_eq!(JobStatus::Expired.to_string(), "expired");
+        assert_eq!(ReportStatus::ToReview.as_str(), "to-review");
+    }
+
+    #[test]
+    fn training_cost() {
+        let costs: Vec<TrainingCost> =
+            serde_json::from_str(r#"["free", "fee-based", "sponsored"]"#).unwrap();
+        assert_eq!(
+            costs,
+            [
+                TrainingCost::Free,
+                TrainingCost::Fee,
+                TrainingCost::Unknown("sponsored".to_string())
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&costs).unwrap(),
+            r#"["free","fee-based","sponsored"]"#
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn closing_dates() {
+        use chrono::{TimeZone, Utc};
+        use reliefweb::common::DocumentDates;
+
+        let resp: ApiResponse<JobFields> = fixture(include_str!("fixtures/job.json"));
+        let job = &resp.data[0].fields;
+        let dates = job.date.as_ref().unwrap();
+        assert_eq!(
+            dates.closing_at().unwrap().unwrap(),
+            Utc.with_ymd_and_hms(2024, 8, 31, 0, 0, 0).unwrap()
+        );
+        assert!(!dates.is_closed_at(&Utc.with_ymd_and_hms(2024, 8, 31, 23, 0, 0).unwrap()));
+        assert!(dates.is_closed_at(&Utc.with_ymd_and_hms(2024, 9, 1, 0, 0, 0).unwrap()));
+        assert!(job.is_closed());
+
+        let resp: ApiResponse<TrainingFields> = fixture(include_str!("fixtures/training.json"));
+        let training = &resp.data[0].fields;
+        let dates = training.date.as_ref().unwrap();
+        assert!(
+            !dates.is_registration_closed_at(&Utc.with_ymd_and_hms(2024, 8, 1, 0, 0, 0).unwrap())
+        );
+        assert!(!dates.is_closed_at(&Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap()));
+        assert!(training.is_registration_closed());
+
+        let dates: DocumentDates = serde_json::from_value(serde_json::json!({
+            "closing": "2024-08-31T12:00:00+02:00",
+            "registration": "not a date",
+            "start": "2024-09-15",
+            "end": ""
+        }))
+        .unwrap();
+        assert!(dates.is_closed_at(&Utc.with_ymd_and_hms(2024, 8, 31, 10, 0, 0).unwrap()));
+        assert!(dates.registration_at().unwrap().is_err());
+        assert!(!dates.is_registration_closed());
+        assert_eq!(
+            dates.start_at().unwrap().unwrap(),
+            Utc.with_ymd_and_hms(2024, 9, 15, 0, 0, 0).unwrap()
+        );
+        assert!(dates.end_at().unwrap().is_err());
+        assert!(dates.created_at().is_none());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn parsed_dates_chrono() {
+        use chrono::{DateTime, TimeZone, Utc};
+        use reliefweb::{Error, common::DocumentDates};
+
+        let resp: ApiResponse<TrainingFields> = fixture(include_str!("fixtures/training.json"));
+        let dates = resp.data[0].fields.date.as_ref().unwrap();
+        let parsed = dates.parsed::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            parsed.registration,
+            Some(Utc.with_ymd_and_hms(2024, 9, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            parsed.end,
+            Some(Utc.with_ymd_and_hms(2024, 10, 15, 0, 0, 0).unwrap())
+        );
+        assert_eq!(parsed.closing, None);
+
+        let dates: DocumentDates = serde_json::from_value(serde_json::json!({
+            "closing": "2024-08-31T12:00:00+02:00",
+            "start": "2024-09-15"
+        }))
+        .unwrap();
+        let parsed = dates.parsed::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            parsed.closing,
+            Some(Utc.with_ymd_and_hms(2024, 8, 31, 10, 0, 0).unwrap())
+        );
+        assert_eq!(
+            parsed.start,
+            Some(Utc.with_ymd_and_hms(2024, 9, 15, 0, 0, 0).unwrap())
+        );
+
+        let dates: DocumentDates =
+            serde_json::from_value(serde_json::json!({ "end": "not a date" })).unwrap();
+        assert!(matches!(
+            dates.parsed::<DateTime<Utc>>(),
+            Err(Error::InvalidDate { field: "end", .. })
+        ));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn parsed_dates_time() {
+        use reliefweb::{Error, common::DocumentDates};
+        use time::{OffsetDateTime, macros::datetime};
+
+        let resp: ApiResponse<JobFields> = fixture(include_str!("fixtures/job.json"));
+        let dates = resp.data[0].fields.date.as_ref().unwrap();
+        let parsed = dates.parsed::<OffsetDateTime>().unwrap();
+        assert_eq!(parsed.closing, Some(datetime!(2024-08-31 00:00 UTC)));
+        assert_eq!(parsed.created, Some(datetime!(2024-08-01 09:00 UTC)));
+        assert_eq!(parsed.event, None);
+
+        let dates: DocumentDates = serde_json::from_value(serde_json::json!({
+            "closing": "2024-08-31T12:00:00+02:00",
+            "start": "2024-09-15"
+        }))
+        .unwrap();
+        let parsed = dates.parsed::<OffsetDateTime>().unwrap();
+        assert_eq!(parsed.closing, Some(datetime!(2024-08-31 12:00 +2)));
+        assert_eq!(parsed.start, Some(datetime!(2024-09-15 00:00 UTC)));
+
+        let dates: DocumentDates =
+            serde_json::from_value(serde_json::json!({ "end": "2024-13-01" })).unwrap();
+        assert!(matches!(
+            dates.parsed::<OffsetDateTime>(),
+            Err(Error::InvalidDate { field: "end", .. })
+        ));
+    }
+
+    #[test]
+    fn field_enums_cover_struct_fields() {
+        const DATES: [&str; 8] = [
+            "date.closing",
+            "date.original",
+            "date.changed",
+            "date.created",
+            "date.event",
+            "date.registration",
+            "date.start",
+            "date.end",
+        ];
+        let dates_except = |used: &[&str]| -> Vec<&str> {
+            DATES
+                .into_iter()
+                .filter(|date| !used.contains(date))
+                .collect()
+        };
+
+        assert_fields_cover::<ReportFields>(&dates_except(&[
+            "date.original",
+            "date.changed",
+            "date.created",
+        ]));
+        assert_fields_cover::<DisasterFields>(&dates_except(&[
+            "date.changed",
+            "date.created",
+            "date.event",
+        ]));
+        assert_fields_cover::<CountryFields>(&dates_except(&["date.changed", "date.created"]));
+        assert_fields_cover::<SourceFields>(&dates_except(&["date.changed", "date.created"]));
+        assert_fields_cover::<JobFields>(&dates_except(&[
+            "date.closing",
+            "date.changed",
+            "date.created",
+        ]));
+        assert_fields_cover::<TrainingFields>(&dates_except(&[
+            "date.changed",
+            "date.created",
+            "date.registration",
+            "date.start",
+            "date.end",
+        ]));
+        assert_fields_cover::<BlogFields>(&dates_except(&["date.changed", "date.created"]));
+        assert_fields_cover::<BookFields>(&dates_except(&["date.changed", "date.created"]));
+        assert_fields_cover::<ReferenceFields>(&[]);
+    }
+
+    /// Asserts that every field of the fields struct of `T`, nested ones included, has a variant in its field enum.
+    ///
+    /// `unused` lists the fields of shared structs the resource does not have, such as the dates of `DocumentDates`
+    /// other than the ones of the resource.
+    fn assert_fields_cover<T: Resource>(unused: &[&str]) {
+        let mut paths = BTreeSet::new();
+        T::deserialize(paths::Tracer {
+            path: "",
+            paths: &mut paths,
+        })
+        .unwrap();
+
+        let missing: Vec<_> = paths
+            .iter()
+            .filter(|path| !unused.contains(&path.as_str()))
+            .filter(|path| T::Field::from_name(path).is_none())
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "{} has no variant for {missing:?}",
+            std::any::type_name::<T::Field>()
+        );
+    }
+
+    /// A deserializer recording the serde path of every struct field, joined with dots as in the API's field names.
+    ///
+    /// Lists are transparent, like in the API: the `url` of every `file` is `file.url`.
+    mod paths {
+        use std::collections::BTreeSet;
+
+        use serde::de::{self, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+
+        type Error = de::value::Error;
+
+        pub struct Tracer<'a> {
+            pub path: &'a str,
+            pub paths: &'a mut BTreeSet<String>,
+        }
+
+        impl<'de> de::Deserializer<'de> for Tracer<'_> {
+            type Error = Error;
+
+            fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.visit_unit()
+            }
+
+            fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.visit_bool(false)
+            }
+
+            fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.visit_i64(0)
+            }
+
+            fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.visit_u64(0)
+            }
+
+            fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.visit_f64(0.0)
+            }
+
+            fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.visit_str("")
+            }
+
+            fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.visit_str("")
+            }
+
+            fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.visit_some(self)
+            }
+
+            fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.visit_seq(Element(Some(self)))
+            }
+
+            fn deserialize_newtype_struct<V: Visitor<'de>>(
+                self,
+                _name: &'static str,
+                visitor: V,
+            ) -> Result<V::Value, Error> {
+                visitor.visit_newtype_struct(self)
+            }
+
+            fn deserialize_struct<V: Visitor<'de>>(
+                self,
+                _name: &'static str,
+                fields: &'static [&'static str],
+                visitor: V,
+            ) -> Result<V::Value, Error> {
+                visitor.visit_map(Fields {
+                    tracer: self,
+                    fields: fields.iter(),
+                    path: String::new(),
+                })
+            }
+
+            serde::forward_to_deserialize_any! {
+                i8 i16 i32 i128 u8 u16 u32 u128 f32 char bytes byte_buf unit unit_struct
+                tuple tuple_struct map enum identifier ignored_any
+            }
+        }
+
+        /// A list of a single element.
+        struct Element<'a>(Option<Tracer<'a>>);
+
+        impl<'de> SeqAccess<'de> for Element<'_> {
+            type Error = Error;
+
+            fn next_element_seed<S: DeserializeSeed<'de>>(
+                &mut self,
+                seed: S,
+            ) -> Result<Option<S::Value>, Error> {
+                self.0
+                    .take()
+                    .map(|tracer| seed.deserialize(tracer))
+                    .transpose()
+            }
+        }
+
+        /// The fields of a struct, each recorded before being traced in turn.
+        struct Fields<'a> {
+            tracer: Tracer<'a>,
+            fields: std::slice::Iter<'static, &'static str>,
+            path: String,
+        }
+
+        impl<'de> MapAccess<'de> for Fields<'_> {
+            type Error = Error;
+
+            fn next_key_seed<K: DeserializeSeed<'de>>(
+                &mut self,
+                seed: K,
+            ) -> Result<Option<K::Value>, Error> {
+                let Some(field) = self.fields.next() else {
+                    return Ok(None);
+                };
+                self.path = match self.tracer.path {
+                    "" => field.to_string(),
+                    parent => format!("{parent}.{field}"),
+                };
+                self.tracer.paths.insert(self.path.clone());
+                seed.deserialize(field.into_deserializer()).map(Some)
+            }
+
+            fn next_value_seed<S: DeserializeSeed<'de>>(
+                &mut self,
+                seed: S,
+            ) -> Result<S::Value, Error> {
+                seed.deserialize(Tracer {
+                    path: &self.path,
+                    paths: self.tracer.paths,
+                })
+            }
+        }
+    }
+}